@@ -0,0 +1,61 @@
+//! A basic 2D example demonstrating panning, zooming and rotating a 2D camera, kept inside some
+//! bounds.
+
+use bevy::prelude::*;
+use bevy_debug_camera::{
+    DebugCamera, DebugCameraBounds, DebugCameraConstraints, DebugCameraMode, DebugCameraOrtho2d,
+    DebugCameraPlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "2D Scene".into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(DebugCameraPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+/// set up a simple 2D scene
+fn setup(mut commands: Commands) {
+    // a grid of sprites
+    for x in -5..=5 {
+        for y in -5..=5 {
+            commands.spawn(SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.5 + x as f32 * 0.1, 0.5 + y as f32 * 0.1, 0.6),
+                    custom_size: Some(Vec2::new(80., 80.)),
+                    ..default()
+                },
+                transform: Transform::from_xyz(x as f32 * 100., y as f32 * 100., 0.),
+                ..default()
+            });
+        }
+    }
+    // camera, which stays at the depth `Camera2dBundle` sets
+    commands
+        .spawn(Camera2dBundle::default())
+        .insert(DebugCamera {
+            mode: DebugCameraMode::Ortho2d,
+            // 2D units are pixels, so we need to move much faster than in 3D
+            speed_translate: 800.,
+            ortho_2d: DebugCameraOrtho2d {
+                rotate: true,
+                ..default()
+            },
+            ..default()
+        })
+        // Keep the camera over the sprites
+        .insert(DebugCameraConstraints {
+            bounds: Some(DebugCameraBounds::Aabb {
+                min: Vec3::new(-500., -500., 0.),
+                max: Vec3::new(500., 500., 0.),
+            }),
+            ..default()
+        });
+}
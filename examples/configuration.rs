@@ -4,9 +4,14 @@
 use bevy::prelude::*;
 use bevy_debug_camera::{
     DebugCamera, DebugCameraActive, DebugCameraPlugin, GamepadBindings, KeyboardBindings,
+    MouseBindings, NavigationScheme,
 };
 
 fn main() {
+    let mut debug_camera_active = DebugCameraActive::default();
+    // Disable keyboard + mouse only
+    debug_camera_active.keymouse = false;
+
     App::new()
         .add_plugins(DefaultPlugins)
         // Each field in `DebugCameraPlugin` can be set directly or picked up from
@@ -26,11 +31,14 @@ fn main() {
                 right: KeyCode::Right,
                 ..default()
             },
-            debug_camera_active: DebugCameraActive {
-                // Disable keyboard + mouse only
-                keymouse: false,
+            mouse_bindings: MouseBindings {
+                // Orbit with the left mouse button instead of the middle one
+                orbit: MouseButton::Left,
                 ..default()
             },
+            // Use Blender/Unity style mouse controls. Only used with keyboard + mouse input
+            navigation_scheme: NavigationScheme::Editor,
+            debug_camera_active,
         })
         .add_systems(Startup, setup)
         .run();
@@ -1,4 +1,450 @@
 use bevy::prelude::*;
+use std::{fmt, num::ParseFloatError, str::FromStr};
+
+/// Prefix of the current version of the [`DebugCameraPose`] string format.
+const POSE_STRING_PREFIX: &str = "dcam1:";
+
+/// The smallest distance we allow between a camera and its focus point. Keeps orbiting and zooming
+/// from collapsing the camera onto the point it's looking at.
+pub(crate) const MIN_FOCUS_DISTANCE: f32 = 0.01;
+
+/// Squared sine of the angle below which we treat two directions as parallel. Cameras can't tell
+/// which way is up when their fwd and up vectors get this close.
+const PARALLEL_EPSILON: f32 = 1e-6;
+
+/// Selects how a [`DebugCamera`] responds to input. The mode can be changed at any point during
+/// runtime, and the camera will continue moving from its current pose.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum DebugCameraMode {
+    /// Free-fly motion. The camera rotates around its own position and translates in the
+    /// direction it is facing.
+    #[default]
+    Fly,
+    /// The camera rotates around its focus point (see [`DebugCamera::focus`]). Moving left, right,
+    /// forward and backward pans both the camera and the focus point, while moving up and down (or
+    /// using the mouse wheel) shrinks and grows the orbit radius.
+    Orbit,
+    /// The camera walks along the ground at eye height, following the settings in
+    /// [`DebugCamera::walk`]. The ground height comes from the [`crate::DebugCameraGround`]
+    /// resource. Moving up and down is replaced by the jump and crouch bindings, and the camera
+    /// always behaves as if `horizon_lock` was set.
+    Walk,
+    /// The camera follows the given entity around, according to the settings in
+    /// [`DebugCamera::follow`]. Movement input is ignored. Switch back to another mode to detach
+    /// the camera, which will continue from wherever it was.
+    Follow(Entity),
+    /// The camera rotates around its focus point like a trackball. Dragging the mouse with the
+    /// arcball button (see [`crate::MouseBindings`]) rolls a virtual sphere filling the window,
+    /// rotating the camera freely in any direction, including roll. The cursor is left free while
+    /// using this mode. Other bindings behave as in [`DebugCameraMode::Orbit`], except that
+    /// `horizon_lock` has no effect.
+    Arcball,
+    /// A strategy game style camera looking down on the ground from above, following the settings
+    /// in [`DebugCamera::top_down`]. Movement pans the camera's focus point along the ground,
+    /// which can also be done by moving the cursor to the edges of the window. The roll bindings
+    /// rotate the view around the focus point, and moving up and down (or using the mouse wheel)
+    /// zooms. The cursor is left free while using this mode.
+    TopDown,
+    /// Controls for 2D cameras, such as those spawned with a [`Camera2dBundle`], following the
+    /// settings in [`DebugCamera::ortho_2d`]. The camera always looks down the -z axis, and stays
+    /// at the depth of its [`Transform`] (such as the one set by [`Camera2dBundle`]), so only the x
+    /// and y of [`DebugCamera::position`] are used. Movement
+    /// and dragging with the pan mouse button move the camera across the xy plane, while moving up
+    /// and down (or using the mouse wheel) zooms by changing the scale of the camera's
+    /// [`OrthographicProjection`]. The mouse wheel zooms towards the cursor. The cursor is left
+    /// free while using this mode.
+    Ortho2d,
+}
+
+impl DebugCameraMode {
+    /// Whether this mode needs a visible cursor that isn't locked to the window.
+    pub(crate) fn uses_cursor(&self) -> bool {
+        matches!(
+            self,
+            DebugCameraMode::Arcball | DebugCameraMode::TopDown | DebugCameraMode::Ortho2d
+        )
+    }
+}
+
+/// The position and orientation of a [`DebugCamera`]. Can be read from a camera with
+/// [`DebugCamera::pose`], and applied to one with [`DebugCamera::set_pose`] or
+/// [`DebugCamera::transition_to`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebugCameraPose {
+    pub position: Vec3,
+    pub fwd: Vec3,
+    pub up: Vec3,
+}
+
+impl DebugCameraPose {
+    /// The rotation from bevy's camera frame of reference (looking down -z, with y up) to this
+    /// pose's orientation.
+    pub fn rotation(&self) -> Quat {
+        Transform::IDENTITY.looking_to(self.fwd, self.up).rotation
+    }
+
+    /// Builds a pose at `position` with the orientation given by `rotation`. This is the inverse
+    /// of [`DebugCameraPose::rotation`].
+    pub fn from_rotation(position: Vec3, rotation: Quat) -> DebugCameraPose {
+        DebugCameraPose {
+            position,
+            fwd: rotation * Vec3::NEG_Z,
+            up: rotation * Vec3::Y,
+        }
+    }
+
+    /// Whether this pose can be applied to a camera: all its values are finite, and `fwd` and `up`
+    /// are non-zero and not parallel, so that they make up a valid orientation.
+    pub fn is_valid(&self) -> bool {
+        let (Some(fwd), Some(up)) = (self.fwd.try_normalize(), self.up.try_normalize()) else {
+            return false;
+        };
+        self.position.is_finite() && fwd.cross(up).length_squared() >= PARALLEL_EPSILON
+    }
+
+    /// Interpolates between this pose and `other`, with `t` going from 0 (this pose) to 1
+    /// (`other`). Orientations are interpolated with a spherical interpolation.
+    pub fn lerp(&self, other: &DebugCameraPose, t: f32) -> DebugCameraPose {
+        DebugCameraPose::from_rotation(
+            self.position.lerp(other.position, t),
+            self.rotation().slerp(other.rotation(), t),
+        )
+    }
+}
+
+/// Formats the pose as a compact string that can be shared, for instance by pasting it into a bug
+/// report, and parsed back with [`str::parse`]. The format is versioned and will keep being parsed
+/// by future versions of this crate. It looks like `dcam1:x,y,z,yaw,pitch,roll`, with the position
+/// in units and the orientation as Euler angles in degrees (applied in yaw, pitch, roll order).
+/// Values are rounded to the nearest millimeter and hundredth of a degree respectively.
+impl fmt::Display for DebugCameraPose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (yaw, pitch, roll) = self.rotation().to_euler(EulerRot::YXZ);
+        let values = [
+            format_rounded(self.position.x, 3),
+            format_rounded(self.position.y, 3),
+            format_rounded(self.position.z, 3),
+            format_rounded(yaw.to_degrees(), 2),
+            format_rounded(pitch.to_degrees(), 2),
+            format_rounded(roll.to_degrees(), 2),
+        ];
+        write!(f, "{POSE_STRING_PREFIX}{}", values.join(","))
+    }
+}
+
+impl FromStr for DebugCameraPose {
+    type Err = ParsePoseError;
+
+    fn from_str(s: &str) -> Result<DebugCameraPose, ParsePoseError> {
+        let s = s.trim();
+        let Some(values) = s.strip_prefix(POSE_STRING_PREFIX) else {
+            return Err(match s.split_once(':') {
+                Some((version, _)) if version.starts_with("dcam") => {
+                    ParsePoseError::UnsupportedVersion(version.to_string())
+                }
+                _ => ParsePoseError::MissingPrefix,
+            });
+        };
+
+        let values = values
+            .split(',')
+            .enumerate()
+            .map(|(index, value)| match value.trim().parse::<f32>() {
+                Ok(value) if value.is_finite() => Ok(value),
+                Ok(_) => Err(ParsePoseError::NotFinite { index }),
+                Err(source) => Err(ParsePoseError::InvalidNumber { index, source }),
+            })
+            .collect::<Result<Vec<f32>, ParsePoseError>>()?;
+        let [x, y, z, yaw, pitch, roll] = values[..] else {
+            return Err(ParsePoseError::WrongValueCount(values.len()));
+        };
+
+        let rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            yaw.to_radians(),
+            pitch.to_radians(),
+            roll.to_radians(),
+        );
+        Ok(DebugCameraPose::from_rotation(Vec3::new(x, y, z), rotation))
+    }
+}
+
+/// Errors that can happen when parsing a [`DebugCameraPose`] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePoseError {
+    /// The string doesn't start with a known prefix, so it's probably not a pose at all.
+    MissingPrefix,
+    /// The string is a pose from a version of the format this crate doesn't know about. Contains
+    /// the unknown version's prefix.
+    UnsupportedVersion(String),
+    /// The string doesn't contain exactly 6 values. Contains the number of values found.
+    WrongValueCount(usize),
+    /// The value at `index` is not a number.
+    InvalidNumber {
+        index: usize,
+        source: ParseFloatError,
+    },
+    /// The value at `index` is infinite or NaN.
+    NotFinite { index: usize },
+}
+
+impl fmt::Display for ParsePoseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePoseError::MissingPrefix => {
+                write!(
+                    f,
+                    "not a camera pose, expected it to start with `{POSE_STRING_PREFIX}`"
+                )
+            }
+            ParsePoseError::UnsupportedVersion(version) => {
+                write!(f, "unsupported camera pose version `{version}`")
+            }
+            ParsePoseError::WrongValueCount(count) => {
+                write!(f, "expected 6 values in camera pose, found {count}")
+            }
+            ParsePoseError::InvalidNumber { index, source } => {
+                write!(f, "camera pose value {index} is not a number: {source}")
+            }
+            ParsePoseError::NotFinite { index } => {
+                write!(f, "camera pose value {index} is not finite")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePoseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParsePoseError::InvalidNumber { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Formats `value` with at most `decimals` decimal places, dropping trailing zeros.
+fn format_rounded(value: f32, decimals: usize) -> String {
+    let formatted = format!("{value:.decimals$}");
+    let formatted = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &formatted
+    };
+    // Avoid writing out negative zero
+    match formatted {
+        "-0" => "0".to_string(),
+        formatted => formatted.to_string(),
+    }
+}
+
+/// A smooth transition of a [`DebugCamera`] from one pose to another. These are started with
+/// [`DebugCamera::transition_to`], and override any input until they finish.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraTransition {
+    from: DebugCameraPose,
+    to: DebugCameraPose,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl DebugCameraTransition {
+    /// The pose the camera will be in once the transition finishes.
+    pub fn target(&self) -> DebugCameraPose {
+        self.to
+    }
+
+    /// Advances the transition by `delta_seconds`, returning the pose the camera should be in and
+    /// whether the transition has finished.
+    pub(crate) fn advance(&mut self, delta_seconds: f32) -> (DebugCameraPose, bool) {
+        self.elapsed += delta_seconds;
+        if self.elapsed >= self.duration {
+            return (self.to, true);
+        }
+        // Smoothstep, so that the camera eases in and out of the motion
+        let t = self.elapsed / self.duration;
+        let t = t * t * (3. - 2. * t);
+        (self.from.lerp(&self.to, t), false)
+    }
+}
+
+/// Settings for smoothing out the motion of a [`DebugCamera`]. All rates are in 1/seconds, and
+/// higher values make the camera more responsive. The smoothing is independent of frame rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraInertia {
+    /// How quickly the camera's velocity approaches the velocity requested by input.
+    pub acceleration: f32,
+    /// How quickly the camera slows down once there is no more translation input.
+    pub damping: f32,
+    /// How quickly the camera's rotation catches up with rotation input.
+    pub rotation_smoothing: f32,
+}
+
+impl Default for DebugCameraInertia {
+    fn default() -> DebugCameraInertia {
+        DebugCameraInertia {
+            acceleration: 8.,
+            damping: 4.,
+            rotation_smoothing: 12.,
+        }
+    }
+}
+
+/// Settings for a [`DebugCamera`] in [`DebugCameraMode::Walk`]. Distances are in units, and speeds
+/// in units/second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraWalk {
+    /// Height of the camera above the ground while standing.
+    pub eye_height: f32,
+    /// Height of the camera above the ground while crouching.
+    pub crouch_eye_height: f32,
+    /// Downwards acceleration (in units/second²) applied while the camera is in the air.
+    pub gravity: f32,
+    /// Vertical speed the camera gets when jumping.
+    pub jump_speed: f32,
+    /// Largest change in ground height the camera will follow, such as when walking up or down
+    /// stairs. The camera falls off larger drops, and larger rises block it like walls.
+    pub step_height: f32,
+}
+
+impl Default for DebugCameraWalk {
+    fn default() -> DebugCameraWalk {
+        DebugCameraWalk {
+            eye_height: 1.7,
+            crouch_eye_height: 1.,
+            gravity: 9.81,
+            jump_speed: 5.,
+            step_height: 0.3,
+        }
+    }
+}
+
+/// A target a [`DebugCamera`] can be locked on to with its `look_at` field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DebugCameraLookAt {
+    /// Look at the position of an entity with a [`GlobalTransform`].
+    Entity(Entity),
+    /// Look at a fixed point in global space.
+    Point(Vec3),
+}
+
+/// Settings for a [`DebugCamera`] in [`DebugCameraMode::Follow`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraFollow {
+    /// Where the camera sits relative to the target, in the target's frame of reference. Note that
+    /// bevy entities face towards -z, so a positive z places the camera behind the target.
+    pub offset: Vec3,
+    /// How long (in seconds) the camera takes to catch up with the target, roughly. Set to 0 to
+    /// attach the camera rigidly.
+    pub lag: f32,
+    /// If true, the camera always looks at the target. Otherwise it faces the same way as the
+    /// target.
+    pub look_at: bool,
+}
+
+impl Default for DebugCameraFollow {
+    fn default() -> DebugCameraFollow {
+        DebugCameraFollow {
+            offset: Vec3::new(0., 2., 6.),
+            lag: 0.2,
+            look_at: true,
+        }
+    }
+}
+
+/// Settings for a [`DebugCamera`] in [`DebugCameraMode::TopDown`]. Zooming moves the camera along
+/// a curve from its lowest to its highest point: the height grows exponentially, so each step of
+/// the mouse wheel changes it by the same proportion, while the pitch changes linearly. Heights are
+/// measured from the ground (see [`crate::DebugCameraGround`]), and pitches are in radians below
+/// the horizon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraTopDown {
+    /// Height of the camera above its focus point when fully zoomed in.
+    pub min_height: f32,
+    /// Height of the camera above its focus point when fully zoomed out.
+    pub max_height: f32,
+    /// Pitch of the camera when fully zoomed in.
+    pub min_pitch: f32,
+    /// Pitch of the camera when fully zoomed out.
+    pub max_pitch: f32,
+    /// Current zoom level, from 0 (fully zoomed in) to 1 (fully zoomed out). This is updated by
+    /// our systems, but can also be set manually.
+    pub zoom: f32,
+    /// Distance (in pixels) from the edges of the window within which the cursor pans the camera.
+    /// Set to 0 to disable edge scrolling.
+    pub edge_scroll_margin: f32,
+}
+
+impl Default for DebugCameraTopDown {
+    fn default() -> DebugCameraTopDown {
+        DebugCameraTopDown {
+            min_height: 5.,
+            max_height: 50.,
+            min_pitch: std::f32::consts::FRAC_PI_4,
+            max_pitch: 75. * std::f32::consts::PI / 180.,
+            zoom: 0.5,
+            edge_scroll_margin: 10.,
+        }
+    }
+}
+
+/// Settings for a [`DebugCamera`] in [`DebugCameraMode::Ortho2d`]. Panning moves the camera at
+/// `speed_translate` multiplied by the projection's scale, so it stays constant on screen. As 2D
+/// units are usually pixels, 2D cameras generally need a much higher `speed_translate` than the
+/// default. To keep the camera inside an area, use [`DebugCameraConstraints`] bounds as with any
+/// other camera.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraOrtho2d {
+    /// Smallest scale the camera's [`OrthographicProjection`] can be zoomed in to.
+    pub min_scale: f32,
+    /// Largest scale the camera's [`OrthographicProjection`] can be zoomed out to.
+    pub max_scale: f32,
+    /// If true, the roll bindings rotate the camera around the center of the view. Defaults to
+    /// false.
+    pub rotate: bool,
+}
+
+impl Default for DebugCameraOrtho2d {
+    fn default() -> DebugCameraOrtho2d {
+        DebugCameraOrtho2d {
+            min_scale: 0.01,
+            max_scale: 100.,
+            rotate: false,
+        }
+    }
+}
+
+/// Settings for zooming a [`DebugCamera`] by narrowing its field of view, like a scope. This only
+/// affects cameras with a perspective [`Projection`]. While zoomed, mouse sensitivity is divided by
+/// the current magnification, so aiming stays precise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugCameraFovZoom {
+    /// How much the view is magnified while the zoom binding is held. Scrolling while zoomed
+    /// changes this.
+    pub magnification: f32,
+    /// Lowest value `magnification` can be set to by scrolling.
+    pub min_magnification: f32,
+    /// Highest value `magnification` can be set to by scrolling.
+    pub max_magnification: f32,
+    /// How quickly (in 1/seconds) the field of view changes when zooming in and out. Set to 0 to
+    /// zoom instantly.
+    pub smoothing: f32,
+    /// Current magnification, where 1 means the camera isn't zoomed in. This is updated by our
+    /// systems.
+    pub current: f32,
+}
+
+impl Default for DebugCameraFovZoom {
+    fn default() -> DebugCameraFovZoom {
+        DebugCameraFovZoom {
+            magnification: 4.,
+            min_magnification: 1.,
+            max_magnification: 20.,
+            smoothing: 15.,
+            current: 1.,
+        }
+    }
+}
 
 /// Any entity with this component will be controllable using the default bindings for
 /// this plugin. For more information on controls, refer to the crate root.
@@ -14,11 +460,157 @@ pub struct DebugCamera {
     /// update this automatically.
     pub position: Vec3,
     /// This is a configurable setting for this camera. It is the speed (in units/second) at which
-    /// the camera should translate when going at full speed.
+    /// the camera should translate when going at full speed. It can be changed at runtime with the
+    /// mouse wheel and the gamepad speed bindings, so it can also be read to display the current
+    /// speed.
     pub speed_translate: f32,
+    /// Lowest value `speed_translate` can be set to with the speed bindings.
+    pub speed_translate_min: f32,
+    /// Highest value `speed_translate` can be set to with the speed bindings.
+    pub speed_translate_max: f32,
+    /// Factor by which `speed_translate` is multiplied for each mouse wheel line or gamepad speed
+    /// button press. Scrolling down or pressing the speed down button divides by this factor
+    /// instead. Should be greater than 1.
+    pub speed_translate_step: f32,
     /// This is a configurable setting for this camera. It is the speed (in radians/second) at
-    /// which the camera should rotate when going at full speed.
+    /// which the camera should rotate when going at full speed. This applies to gamepad sticks and
+    /// keys, but not to the mouse (see `mouse_sensitivity`).
     pub speed_rotate: f32,
+    /// This is a configurable setting for this camera. It is the angle (in radians) the camera
+    /// rotates for every pixel the mouse moves. This does not depend on frame rate.
+    pub mouse_sensitivity: f32,
+    /// Multiplier applied to both translation and rotation speed while the boost binding is held.
+    pub boost_multiplier: f32,
+    /// Multiplier applied to both translation and rotation speed while the precision binding is
+    /// held. Both multipliers are applied if both bindings are held.
+    pub precision_multiplier: f32,
+    /// The way this camera responds to input. Defaults to [`DebugCameraMode::Fly`].
+    pub mode: DebugCameraMode,
+    /// If true, the camera behaves like a first person camera: it yaws around the world's up axis
+    /// (positive y) instead of its own, pitch is limited to stop it flipping over, and rolling is
+    /// disabled. Any existing roll is removed when this is turned on. Defaults to false.
+    pub horizon_lock: bool,
+    /// Distance along `fwd` from the camera's position to its focus point. This is the orbit
+    /// radius when in [`DebugCameraMode::Orbit`].
+    pub focus_distance: f32,
+    /// If true, the camera's [`Projection`] is switched to an orthographic projection showing the
+    /// same area a perspective projection would show at the focus distance. Changing the focus
+    /// distance (such as by zooming in [`DebugCameraMode::Orbit`]) zooms the orthographic view.
+    /// Setting this back to false restores the previous perspective projection. Defaults to false,
+    /// though cameras spawned with an orthographic projection have it set for them, and keep their
+    /// own projection until this is changed.
+    pub orthographic: bool,
+    /// Time (in seconds) taken by smooth transitions started by our systems, such as when
+    /// focusing on an entity. Set to 0 to make them instant.
+    pub transition_duration: f32,
+    /// The transition this camera is currently going through, if any. Set this to `None` to
+    /// cancel it.
+    pub transition: Option<DebugCameraTransition>,
+    /// The pose this camera was in right before it last jumped somewhere instantly, such as through
+    /// [`DebugCamera::set_pose`] or a [`crate::DebugCameraCommand`]. Our systems record it into the
+    /// [`crate::DebugCameraHistory`] and then clear it. Set this when moving the camera by hand for
+    /// the move to be recorded.
+    pub jumped_from: Option<DebugCameraPose>,
+    /// If set, the camera always faces this target, and its focus point is kept on it. Translating
+    /// sideways then circles around the target. Rotating with the mouse or sticks has no effect,
+    /// though the camera can still roll. Defaults to `None`.
+    pub look_at: Option<DebugCameraLookAt>,
+    /// Settings used while the camera is in [`DebugCameraMode::Walk`].
+    pub walk: DebugCameraWalk,
+    /// Settings used while the camera is in [`DebugCameraMode::Follow`].
+    pub follow: DebugCameraFollow,
+    /// Settings used while the camera is in [`DebugCameraMode::TopDown`].
+    pub top_down: DebugCameraTopDown,
+    /// Settings used while the camera is in [`DebugCameraMode::Ortho2d`].
+    pub ortho_2d: DebugCameraOrtho2d,
+    /// Settings for zooming by changing the field of view.
+    pub fov_zoom: DebugCameraFovZoom,
+    /// If set, the camera accelerates and decelerates smoothly instead of applying input
+    /// instantly. Defaults to `None`.
+    pub inertia: Option<DebugCameraInertia>,
+    /// Current velocity of the camera in global space (in units/second). This is updated by our
+    /// systems, and is only carried over between frames when `inertia` is set.
+    pub velocity: Vec3,
+    /// Current angular velocity of the camera (in radians/second) as yaw, pitch and roll. This is
+    /// updated by our systems, and is only carried over between frames when `inertia` is set.
+    pub angular_velocity: Vec3,
+}
+
+impl DebugCamera {
+    /// The point this camera orbits around when in [`DebugCameraMode::Orbit`]. It always sits
+    /// `focus_distance` units in front of the camera.
+    pub fn focus(&self) -> Vec3 {
+        self.position + self.fwd.normalize() * self.focus_distance
+    }
+
+    /// Moves the focus point to `focus` by turning the camera to face it and updating
+    /// `focus_distance` to match. The camera position is left unchanged. Does nothing if `focus`
+    /// is the camera's own position.
+    pub fn set_focus(&mut self, focus: Vec3) {
+        let offset = focus - self.position;
+        let distance = offset.length();
+        if distance > MIN_FOCUS_DISTANCE {
+            let fwd = offset / distance;
+            // Facing straight along the up vector leaves no way to tell which way is up, so we
+            // rotate up along with fwd instead, which keeps it perpendicular
+            if fwd.cross(self.up).length_squared() < PARALLEL_EPSILON {
+                self.up = Quat::from_rotation_arc(self.fwd.normalize(), fwd) * self.up;
+            }
+            self.fwd = fwd;
+            self.focus_distance = distance;
+        }
+    }
+
+    /// The camera's current pose.
+    pub fn pose(&self) -> DebugCameraPose {
+        DebugCameraPose {
+            position: self.position,
+            fwd: self.fwd,
+            up: self.up,
+        }
+    }
+
+    /// Moves the camera to `pose` instantly, cancelling any ongoing transition. The camera is
+    /// also stopped, so that its inertia doesn't carry it away from `pose`. The pose it jumped from
+    /// is kept in `jumped_from`.
+    pub fn set_pose(&mut self, pose: DebugCameraPose) {
+        self.mark_jump();
+        self.place(pose);
+    }
+
+    /// Moves the camera to `pose` like [`DebugCamera::set_pose`], but without counting it as a
+    /// jump. Used for playback, which moves the camera every frame.
+    pub(crate) fn place(&mut self, pose: DebugCameraPose) {
+        self.position = pose.position;
+        self.fwd = pose.fwd;
+        self.up = pose.up;
+        self.transition = None;
+        self.velocity = Vec3::ZERO;
+        self.angular_velocity = Vec3::ZERO;
+    }
+
+    /// Remembers the current pose in `jumped_from`, unless the camera already jumped since it was
+    /// last recorded.
+    pub(crate) fn mark_jump(&mut self) {
+        if self.jumped_from.is_none() {
+            self.jumped_from = Some(self.pose());
+        }
+    }
+
+    /// Smoothly moves the camera to `pose` over `duration` seconds. If `duration` is not positive,
+    /// the camera is moved instantly instead.
+    pub fn transition_to(&mut self, pose: DebugCameraPose, duration: f32) {
+        if duration > 0. {
+            self.transition = Some(DebugCameraTransition {
+                from: self.pose(),
+                to: pose,
+                duration,
+                elapsed: 0.,
+            });
+        } else {
+            self.set_pose(pose);
+        }
+    }
 }
 
 impl Default for DebugCamera {
@@ -28,7 +620,70 @@ impl Default for DebugCamera {
             fwd: Vec3::new(1., 0., 0.),
             position: Vec3::default(),
             speed_translate: 10.,
+            speed_translate_min: 0.1,
+            speed_translate_max: 1000.,
+            speed_translate_step: 1.2,
             speed_rotate: std::f32::consts::FRAC_PI_4,
+            mouse_sensitivity: 0.004,
+            boost_multiplier: 4.,
+            precision_multiplier: 0.25,
+            mode: DebugCameraMode::Fly,
+            horizon_lock: false,
+            focus_distance: 5.,
+            orthographic: false,
+            transition_duration: 0.5,
+            transition: None,
+            jumped_from: None,
+            look_at: None,
+            walk: DebugCameraWalk::default(),
+            follow: DebugCameraFollow::default(),
+            top_down: DebugCameraTopDown::default(),
+            ortho_2d: DebugCameraOrtho2d::default(),
+            fov_zoom: DebugCameraFovZoom::default(),
+            inertia: None,
+            velocity: Vec3::ZERO,
+            angular_velocity: Vec3::ZERO,
         }
     }
 }
+
+/// Region of the world a [`DebugCamera`] is kept inside of. See [`DebugCameraConstraints`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DebugCameraBounds {
+    /// An axis-aligned box spanning from `min` to `max`.
+    Aabb { min: Vec3, max: Vec3 },
+    /// A sphere centered at `center`.
+    Sphere { center: Vec3, radius: f32 },
+}
+
+/// Optional constraints on where a [`DebugCamera`] can move. Add this component to the same entity
+/// as the camera, and it will be enforced every frame after the camera moves.
+#[derive(Debug, Clone, Component)]
+pub struct DebugCameraConstraints {
+    /// If set, the camera's position is clamped to stay inside these bounds.
+    pub bounds: Option<DebugCameraBounds>,
+    /// If true, the camera is pushed out of any entity with a [`DebugCameraCollider`].
+    pub collide: bool,
+    /// Radius of the sphere around the camera used for collisions. This keeps the camera from
+    /// getting close enough to colliders for their geometry to be clipped by the near plane.
+    pub radius: f32,
+}
+
+impl Default for DebugCameraConstraints {
+    fn default() -> DebugCameraConstraints {
+        DebugCameraConstraints {
+            bounds: None,
+            collide: true,
+            radius: 0.2,
+        }
+    }
+}
+
+/// Any entity with this component and a [`GlobalTransform`] will block debug cameras that have
+/// collisions enabled through [`DebugCameraConstraints`]. Shapes are centered on the entity's
+/// position, and follow its rotation and scale.
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub enum DebugCameraCollider {
+    Sphere { radius: f32 },
+    Box { half_extents: Vec3 },
+}
@@ -0,0 +1,39 @@
+use crate::components::{DebugCameraMode, DebugCameraPose};
+use bevy::prelude::*;
+
+/// Sent to make debug cameras smoothly move to frame an entity, keeping their current viewing
+/// direction. The entity's [`bevy::render::primitives::Aabb`] (if any) and [`GlobalTransform`] are
+/// used to work out how far back the camera needs to be for the entity to fit in view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct DebugCameraFocus {
+    /// The entity to focus on.
+    pub target: Entity,
+    /// The debug camera that should focus on the entity, or `None` for all debug cameras.
+    pub camera: Option<Entity>,
+}
+
+/// Sent to steer debug cameras from code, such as gameplay code, consoles or test scripts. These
+/// are applied before any input is handled, so the camera's basis is kept consistent.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct DebugCameraCommand {
+    /// What the camera should do.
+    pub action: DebugCameraAction,
+    /// The debug camera the command applies to, or `None` for all debug cameras.
+    pub camera: Option<Entity>,
+}
+
+/// Actions that can be sent to debug cameras through [`DebugCameraCommand`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DebugCameraAction {
+    /// Moves the camera to `pose`. If `smooth` is true, the camera transitions there over its
+    /// `transition_duration`, and otherwise it jumps there instantly. Invalid poses (see
+    /// [`DebugCameraPose::is_valid`]) are ignored.
+    Teleport { pose: DebugCameraPose, smooth: bool },
+    /// Turns the camera to face a point in global space, without moving it. The focus distance is
+    /// updated so the point becomes the camera's focus point.
+    LookAt(Vec3),
+    /// Sets the camera's translation speed, keeping it within the camera's speed range.
+    SetSpeed(f32),
+    /// Switches the camera to another mode.
+    SetMode(DebugCameraMode),
+}
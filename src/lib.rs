@@ -22,8 +22,8 @@
 //! fn main() {
 //!     App::new()
 //!         .add_plugins(DefaultPlugins)
-//!         .add_plugin(DebugCameraPlugin::default())
-//!         .add_startup_system(setup)
+//!         .add_plugins(DebugCameraPlugin::default())
+//!         .add_systems(Startup, setup)
 //!         .run();
 //! }
 //!
@@ -44,31 +44,231 @@
 //!
 //! ## Mouse + Keyboard
 //!
-//! | Action        | Binding  |
-//! |---------------|----------|
-//! | Move forward  | `W`      |
-//! | Move backward | `S`      |
-//! | Move left     | `A`      |
-//! | Move right    | `D`      |
-//! | Move up       | `Lshift` |
-//! | Move down     | `Space`  |
-//! | Yaw           | Mouse X  |
-//! | pitch         | Mouse Y  |
-//! | Roll left     | `Q`      |
-//! | Roll right    | `E`      |
+//! | Action                | Binding                |
+//! |-----------------------|------------------------|
+//! | Move forward          | `W`                    |
+//! | Move backward         | `S`                    |
+//! | Move left             | `A`                    |
+//! | Move right            | `D`                    |
+//! | Move up               | `Lshift`               |
+//! | Move down             | `Space`                |
+//! | Yaw                   | Mouse X                |
+//! | pitch                 | Mouse Y                |
+//! | Roll left             | `Q`                    |
+//! | Roll right            | `E`                    |
+//! | Change speed          | Mouse wheel            |
+//! | Boost (4x)            | `Lctrl`                |
+//! | Precision (0.25x)     | `Lalt`                 |
+//! | Jump (walk mode)      | `Space`                |
+//! | Crouch (walk mode)    | `C`                    |
+//! | Focus selection       | `F`                    |
+//! | Follow selection      | `T`                    |
+//! | Look at selection     | `L`                    |
+//! | Toggle orthographic   | `Numpad5`              |
+//! | Front view            | `Numpad1`              |
+//! | Right view            | `Numpad3`              |
+//! | Top view              | `Numpad7`              |
+//! | Back/left/bottom view | `Lctrl` + view binding |
+//! | Zoom in (4x)          | Hold right mouse       |
+//! | Change zoom           | Right mouse + wheel    |
+//! | Dolly zoom            | `Z` + mouse wheel      |
+//! | Near plane out/in     | `]` / `[`              |
+//! | Far plane out/in      | `=` / `-`              |
+//! | Reset clip planes     | `\`                    |
+//! | Recall bookmark       | `1` - `9`              |
+//! | Save bookmark         | `Lctrl` + `1` - `9`    |
+//! | Log pose string       | `P`                    |
+//! | History back          | `,`                    |
+//! | History forward       | `.`                    |
+//! | Record path           | `F9`                   |
+//! | Play path             | `F10`                  |
+//! | Add keyframe          | `K`                    |
+//! | Remove last keyframe  | `Backspace`            |
+//! | Play flythrough       | `F8`                   |
+//!
+//! ## Mouse (editor navigation scheme)
+//!
+//! When using `NavigationScheme::Editor`, the cursor is left free and the mouse only moves the
+//! camera while a button is held down. The keyboard bindings above only apply while holding the
+//! look button.
+//!
+//! | Action              | Binding                 |
+//! |---------------------|-------------------------|
+//! | Fly (look + move)   | Hold right mouse        |
+//! | Orbit around focus  | Hold middle mouse       |
+//! | Pan                 | `Lshift` + middle mouse |
+//! | Dolly to/from focus | Mouse wheel             |
+//!
+//! Changing speed multiplies or divides `DebugCamera::speed_translate` by `speed_translate_step`,
+//! keeping it between `speed_translate_min` and `speed_translate_max`. Holding boost or precision
+//! multiplies both translation and rotation speed by `DebugCamera::boost_multiplier` or
+//! `DebugCamera::precision_multiplier` respectively. Mouse look rotates the camera by
+//! `DebugCamera::mouse_sensitivity` radians per pixel, regardless of frame rate.
 //!
 //! ## Controller
 //!
-//! | Action          | Binding    |
-//! |-----------------|------------|
-//! | Move fwd/bwd    | Lstick Y   |
-//! | Move left/right | Lstick X   |
-//! | Move up         | `RTrigger` |
-//! | Move down       | `LTrigger` |
-//! | Yaw             | Rstick X   |
-//! | pitch           | Lstick Y   |
-//! | Roll left       | `LBumper`  |
-//! | Roll right      | `RBumper`  |
+//! | Action              | Binding                             |
+//! |---------------------|-------------------------------------|
+//! | Move fwd/bwd        | Lstick Y                            |
+//! | Move left/right     | Lstick X                            |
+//! | Move up             | `RTrigger`                          |
+//! | Move down           | `LTrigger`                          |
+//! | Yaw                 | Rstick X                            |
+//! | pitch               | Lstick Y                            |
+//! | Roll left           | `LBumper`                           |
+//! | Roll right          | `RBumper`                           |
+//! | Speed up            | `DPadUp`                            |
+//! | Speed down          | `DPadDown`                          |
+//! | Boost (4x)          | `LStick` press                      |
+//! | Precision (0.25x)   | `RStick` press                      |
+//! | Jump (walk mode)    | `South`                             |
+//! | Crouch (walk mode)  | `East`                              |
+//! | Focus selection     | `North`                             |
+//! | Follow selection    | `West`                              |
+//! | Look at selection   | `DPadRight`                         |
+//! | Toggle orthographic | `DPadLeft`                          |
+//! | Near plane out/in   | `Select` + `DPadUp` / `DPadDown`    |
+//! | Far plane out/in    | `Select` + `DPadRight` / `DPadLeft` |
+//! | Reset clip planes   | `Select` + `Start`                  |
+//!
+//! # Camera Modes
+//!
+//! Each [`DebugCamera`] has a `mode` field that can be changed at any time during runtime. Cameras
+//! keep their current pose when switching modes. The following modes are available:
+//!
+//! * `DebugCameraMode::Fly` (default): The camera rotates around its own position and moves in the
+//!   direction it's facing.
+//! * `DebugCameraMode::Orbit`: The camera rotates around a focus point placed `focus_distance`
+//!   units in front of it, which can be set with `DebugCamera::set_focus`. Moving up/down, the
+//!   triggers and the mouse wheel change the orbit radius, while the other movement bindings pan
+//!   the camera and focus point together.
+//! * `DebugCameraMode::Walk`: The camera walks on the ground at eye height, with gravity, jumping
+//!   and crouching configured through `DebugCamera::walk`. The ground's height is provided by
+//!   inserting a [`DebugCameraGround`] resource wrapping your own [`GroundQuery`] implementation
+//!   (such as a raycaster or heightmap). Without it, cameras walk on a flat plane at y = 0. Cameras
+//!   climb steps up to `step_height`, and slide along anything taller as if it were a wall.
+//! * `DebugCameraMode::Follow(entity)`: The camera follows an entity around with the offset, lag
+//!   and look-at behaviour configured through `DebugCamera::follow`. Switching back to any other
+//!   mode detaches the camera, leaving it where it was. The follow binding toggles following the
+//!   entity in [`DebugCameraSelection`].
+//! * `DebugCameraMode::Arcball`: The camera rotates around its focus point like a trackball while
+//!   dragging with the left mouse button, including roll when dragging near the edges of the
+//!   window. The cursor is left free in this mode. Other bindings behave as in orbit mode.
+//! * `DebugCameraMode::TopDown`: A strategy game camera looking down on the ground. The movement
+//!   bindings and moving the cursor to the edges of the window pan along the ground, the roll
+//!   bindings rotate around the center of the view, and moving up/down or the mouse wheel zoom.
+//!   Zooming changes both the camera's height and pitch along a curve configured through
+//!   `DebugCamera::top_down`. The ground's height comes from the [`DebugCameraGround`] resource, as
+//!   in walk mode.
+//! * `DebugCameraMode::Ortho2d`: Controls for 2D cameras spawned with a `Camera2dBundle`. The
+//!   movement bindings and dragging with the middle mouse button pan the camera, while moving
+//!   up/down or the mouse wheel zoom by changing the `OrthographicProjection` scale. The mouse
+//!   wheel zooms towards the cursor. Rotating with the roll bindings and the zoom limits are
+//!   configured through `DebugCamera::ortho_2d`. The camera always looks down -z and stays at the
+//!   depth set by the `Camera2dBundle`, which keeps sprites in view, so only the x and y of
+//!   `DebugCamera::position` matter. Bounds can be set with [`DebugCameraConstraints`]. See the
+//!   `2d_scene` example.
+//!
+//! Setting `DebugCamera::horizon_lock` makes any mode other than arcball behave like a first
+//! person camera. The camera yaws around the world's up axis, can't pitch past straight up or
+//! down and never rolls.
+//!
+//! The focus binding smoothly moves cameras to frame the entity in the [`DebugCameraSelection`]
+//! resource, which should be set by your own selection logic. You can also focus on any entity by
+//! sending a [`DebugCameraFocus`] event. Afterwards, the camera's focus point is the entity's
+//! center, so orbiting will orbit around it.
+//!
+//! Setting `DebugCamera::look_at` to an entity or point locks the camera on to it. The camera keeps
+//! facing the target while moving, so strafing circles around it. The look at binding toggles this
+//! for the entity in [`DebugCameraSelection`]. For example, to lock every debug camera on to the
+//! world's origin:
+//!
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_debug_camera::{DebugCamera, DebugCameraLookAt};
+//!
+//! fn look_at_origin(mut cameras: Query<&mut DebugCamera>) {
+//!     for mut camera in cameras.iter_mut() {
+//!         camera.look_at = Some(DebugCameraLookAt::Point(Vec3::ZERO));
+//!     }
+//! }
+//! ```
+//!
+//! To keep a camera inside the playable area or out of level geometry, add a
+//! [`DebugCameraConstraints`] component to it. This can clamp the camera to an AABB or sphere, and
+//! push it out of any entity with a [`DebugCameraCollider`] shape.
+//!
+//! The toggle orthographic binding switches cameras between perspective and orthographic
+//! projections through their `DebugCamera::orthographic` field. The orthographic view shows the
+//! same area the perspective view did at the focus distance, so the framing is preserved, and
+//! zooming in orbit mode keeps working. Cameras spawned with an orthographic projection keep it
+//! until toggled. The view bindings smoothly move cameras to look at their focus point along one of
+//! the world's axes, which combined with an orthographic projection helps with inspecting alignment
+//! issues.
+//!
+//! Holding the zoom button narrows the field of view of cameras with a perspective projection, like
+//! a scope, and scrolling while holding it changes how far it zooms in. Mouse sensitivity is
+//! divided by the magnification while zoomed. These are configured through `DebugCamera::fov_zoom`.
+//! Since the editor navigation scheme uses the right mouse button to look around, zooming is only
+//! available with the fly scheme. Scrolling while holding the dolly zoom binding changes the field
+//! of view while moving the camera along its view direction, keeping the area around the focus
+//! point the same size on screen.
+//!
+//! The clip plane bindings move the near and far planes of every debug camera, which is handy for
+//! seeing through walls into interiors. The planes are set through the [`DebugCameraClipPlanes`]
+//! resource, which can also be changed directly. Its `set_near` and `set_far` methods keep the
+//! planes within configurable limits and at least `min_gap` apart, and `reset` restores each
+//! camera's original planes.
+//!
+//! The bookmark bindings save the current pose into one of nine slots, and recall it later with a
+//! smooth transition over `DebugCamera::transition_duration` (set it to 0 to jump instantly). Saved
+//! poses are logged as tracing events, and are kept in the [`DebugCameraBookmarks`] resource, which
+//! can also be read and written directly.
+//!
+//! The camera also keeps a history of the places it's been in the [`DebugCameraHistory`] resource,
+//! which the history bindings step back and forward through like a browser. Poses are recorded
+//! right before the camera jumps somewhere else (such as when focusing, recalling a bookmark or
+//! being teleported), and whenever it stays still for a moment.
+//!
+//! The record path binding records the camera's flight path into the [`DebugCameraPathRecorder`]
+//! resource, sampling its pose every frame or at a fixed `interval`, until pressed again. The play
+//! path binding then flies every camera along the recorded [`DebugCameraPath`], at the recorder's
+//! `speed` and optionally `looping`. Playback follows the recorded timeline rather than frames, so
+//! the camera passes through exactly the same poses at any frame rate, which makes it easy to
+//! reproduce performance captures and visual bugs. With the `persist` feature, paths can be saved
+//! and loaded with `DebugCameraPath::save` and `DebugCameraPath::load`.
+//!
+//! For authored flythroughs, the keyframe bindings drop keyframes at the current pose into the
+//! [`DebugCameraFlythrough`] resource, and the play flythrough binding flies every camera through
+//! them along a smooth Catmull-Rom spline, with orientations interpolated spherically between
+//! keyframes. New keyframes are reached `segment_duration` seconds after the previous one, and the
+//! timing of each keyframe can be tweaked afterwards through the [`DebugCameraSpline`]. Closed
+//! splines loop back to their first keyframe. With the `persist` feature, splines can be saved and
+//! loaded like paths.
+//!
+//! To share a viewpoint, for example in a bug report, the copy pose binding logs the current pose
+//! as a compact string such as `dcam1:1,2.5,-3,90,0,0`. The same string is produced by formatting a
+//! [`DebugCameraPose`] with `to_string`, and parsing it back with `str::parse` gives the pose to
+//! pass to `DebugCamera::transition_to`. The format is versioned and stays stable across crate
+//! versions, and invalid strings are reported through [`ParsePoseError`].
+//!
+//! Gameplay code, consoles and test scripts can steer cameras by sending [`DebugCameraCommand`]
+//! events rather than changing `DebugCamera` fields directly. Commands can teleport cameras to a
+//! pose (instantly or with a transition), make them look at a point, set their speed or switch
+//! their mode, and target either a single camera or all of them. They are applied before any input
+//! is handled.
+//!
+//! Cameras can also be given an optional [`DebugCameraInertia`] through their `inertia` field. This
+//! makes them accelerate, decelerate and rotate smoothly, which is handy when recording footage.
+//!
+//! # Persistence
+//!
+//! With the optional `persist` feature, adding a `DebugCameraPersistPlugin` alongside
+//! [`DebugCameraPlugin`] saves the pose and speeds of the debug camera, along with its bookmarks,
+//! to a RON file. The path is configured through its `DebugCameraPersistence` resource
+//! (`debug_camera.ron` by default). The state is loaded once cameras are spawned on startup and
+//! saved on exit, and can also be saved or loaded at any point by sending a `DebugCameraPersist`
+//! event. Invalid poses in the file, such as from hand editing, are logged and skipped.
 //!
 //! # Configuring Plugin
 //!
@@ -77,6 +277,8 @@
 //!
 //! * Keyboard bindings
 //! * Gamepad bindings
+//! * Mouse bindings
+//! * Navigation scheme
 //! * Accepted input
 //!
 //! All these customisation are exposed as resources, which are constantly read and can be modified
@@ -90,11 +292,15 @@
 //! };
 //!
 //! fn main() {
+//!     let mut debug_camera_active = DebugCameraActive::default();
+//!     // Disable keyboard + mouse only
+//!     debug_camera_active.keymouse = false;
+//!
 //!     App::new()
 //!         .add_plugins(DefaultPlugins)
 //!         // Each field in `DebugCameraPlugin` can be set directly or picked up from
 //!         // default.
-//!         .add_plugin(DebugCameraPlugin {
+//!         .add_plugins(DebugCameraPlugin {
 //!             gamepad_bindings: GamepadBindings {
 //!                 // Overrides only the roll buttons
 //!                 roll_left: GamepadButtonType::West,
@@ -109,13 +315,10 @@
 //!                 right: KeyCode::Right,
 //!                 ..default()
 //!             },
-//!             debug_camera_active: DebugCameraActive {
-//!                 // Disable keyboard + mouse only
-//!                 keymouse: false,
-//!                 ..default()
-//!             },
+//!             debug_camera_active,
+//!             ..default()
 //!         })
-//!         .add_startup_system(setup)
+//!         .add_systems(Startup, setup)
 //!         .run();
 //! }
 //!
@@ -125,11 +328,30 @@
 //! ```
 
 mod components;
+mod events;
+#[cfg(feature = "persist")]
+mod persist;
 mod resources;
 mod systems;
 
-pub use components::DebugCamera;
-pub use resources::{ActiveGamepad, DebugCameraActive, GamepadBindings, KeyboardBindings};
+pub use components::{
+    DebugCamera, DebugCameraBounds, DebugCameraCollider, DebugCameraConstraints, DebugCameraFollow,
+    DebugCameraFovZoom, DebugCameraInertia, DebugCameraLookAt, DebugCameraMode, DebugCameraOrtho2d,
+    DebugCameraPose, DebugCameraTopDown, DebugCameraTransition, DebugCameraWalk, ParsePoseError,
+};
+pub use events::{DebugCameraAction, DebugCameraCommand, DebugCameraFocus};
+#[cfg(feature = "persist")]
+pub use persist::{
+    DebugCameraPersist, DebugCameraPersistPlugin, DebugCameraPersistence, DebugCameraState,
+    PersistError,
+};
+pub use resources::{
+    ActiveGamepad, DebugCameraActive, DebugCameraBookmarks, DebugCameraClipPlanes,
+    DebugCameraFlythrough, DebugCameraGround, DebugCameraHistory, DebugCameraKeyframe,
+    DebugCameraPath, DebugCameraPathRecorder, DebugCameraPathSample, DebugCameraPathState,
+    DebugCameraSelection, DebugCameraSpline, FlatGround, GamepadBindings, GroundQuery,
+    KeyboardBindings, MouseBindings, NavigationScheme, BOOKMARK_SLOTS,
+};
 
 use bevy::prelude::*;
 
@@ -137,17 +359,53 @@ use bevy::prelude::*;
 pub struct DebugCameraPlugin {
     pub gamepad_bindings: resources::GamepadBindings,
     pub keyboard_bindings: resources::KeyboardBindings,
+    pub mouse_bindings: resources::MouseBindings,
+    pub navigation_scheme: resources::NavigationScheme,
     pub debug_camera_active: resources::DebugCameraActive,
 }
 
 impl Plugin for DebugCameraPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(resources::ActiveGamepad::default())
+        app.add_event::<events::DebugCameraFocus>()
+            .add_event::<events::DebugCameraCommand>()
+            .insert_resource(resources::ActiveGamepad::default())
+            .insert_resource(resources::DebugCameraSelection::default())
+            .insert_resource(resources::DebugCameraClipPlanes::default())
+            .insert_resource(resources::DebugCameraBookmarks::default())
+            .insert_resource(resources::DebugCameraHistory::default())
+            .insert_resource(resources::DebugCameraPathRecorder::default())
+            .insert_resource(resources::DebugCameraFlythrough::default())
             .insert_resource(self.debug_camera_active.clone())
             .insert_resource(self.gamepad_bindings.clone())
             .insert_resource(self.keyboard_bindings.clone())
-            .add_systems(Update, systems::camera_movement_system)
-            .add_systems(Update, systems::camera_update_system)
+            .insert_resource(self.mouse_bindings.clone())
+            .insert_resource(self.navigation_scheme)
+            .add_systems(
+                Update,
+                (
+                    systems::camera_command_system,
+                    systems::camera_movement_system,
+                    systems::camera_arcball_system,
+                    systems::camera_focus_system,
+                    systems::camera_projection_system,
+                    systems::camera_fov_system,
+                    systems::camera_clip_system,
+                    systems::camera_bookmark_system,
+                    systems::camera_history_system,
+                    systems::camera_copy_pose_system,
+                    systems::camera_transition_system,
+                    systems::camera_follow_system,
+                    systems::camera_walk_system,
+                    systems::camera_top_down_system,
+                    systems::camera_ortho_2d_system,
+                    systems::camera_constraint_system,
+                    systems::camera_look_at_system,
+                    systems::camera_path_system,
+                    systems::camera_flythrough_system,
+                    systems::camera_update_system,
+                )
+                    .chain(),
+            )
             .add_systems(Update, systems::cursor_grab_system)
             .add_systems(Update, systems::gamepad_connections);
     }
@@ -0,0 +1,306 @@
+use crate::{
+    components::{DebugCamera, DebugCameraPose},
+    resources::{DebugCameraBookmarks, DebugCameraPath, DebugCameraSpline, BOOKMARK_SLOTS},
+};
+use bevy::{
+    app::AppExit,
+    prelude::*,
+    utils::tracing::{event, Level},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where and when the state of debug cameras is saved to disk. Part of
+/// [`DebugCameraPersistPlugin`], and can be changed at runtime.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DebugCameraPersistence {
+    /// Path of the RON file the state is saved to and loaded from.
+    pub path: PathBuf,
+    /// If true, the state is loaded once cameras have been spawned on startup. A missing file is
+    /// not treated as an error.
+    pub load_on_startup: bool,
+    /// If true, the state is saved when the app exits.
+    pub save_on_exit: bool,
+}
+
+impl Default for DebugCameraPersistence {
+    fn default() -> DebugCameraPersistence {
+        DebugCameraPersistence {
+            path: PathBuf::from("debug_camera.ron"),
+            load_on_startup: true,
+            save_on_exit: true,
+        }
+    }
+}
+
+/// Send this event to save or load the state of debug cameras on demand, using the path in
+/// [`DebugCameraPersistence`].
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCameraPersist {
+    Save,
+    Load,
+}
+
+/// Everything we persist about debug cameras: the pose and speeds of a camera, and the
+/// [`DebugCameraBookmarks`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DebugCameraState {
+    pub pose: DebugCameraPose,
+    pub speed_translate: f32,
+    pub speed_rotate: f32,
+    pub bookmarks: [Option<DebugCameraPose>; BOOKMARK_SLOTS],
+}
+
+impl DebugCameraState {
+    /// Captures the state of `camera` and `bookmarks`.
+    pub fn capture(camera: &DebugCamera, bookmarks: &DebugCameraBookmarks) -> DebugCameraState {
+        DebugCameraState {
+            pose: camera.pose(),
+            speed_translate: camera.speed_translate,
+            speed_rotate: camera.speed_rotate,
+            bookmarks: bookmarks.0,
+        }
+    }
+
+    /// Moves `camera` to the saved pose instantly, and restores its speeds. Does nothing if the
+    /// saved pose is invalid (see [`DebugCameraPose::is_valid`]), as it would break the camera.
+    pub fn apply_to_camera(&self, camera: &mut DebugCamera) {
+        if !self.pose.is_valid() {
+            return;
+        }
+        camera.set_pose(self.pose);
+        camera.speed_translate = self.speed_translate;
+        camera.speed_rotate = self.speed_rotate;
+    }
+
+    /// Writes this state to the RON file at `path`.
+    pub fn save(&self, path: &Path) -> Result<(), PersistError> {
+        save_ron(self, path)
+    }
+
+    /// Reads a state from the RON file at `path`.
+    pub fn load(path: &Path) -> Result<DebugCameraState, PersistError> {
+        load_ron(path)
+    }
+}
+
+impl DebugCameraPath {
+    /// Writes this path to the RON file at `path`.
+    pub fn save(&self, path: &Path) -> Result<(), PersistError> {
+        save_ron(self, path)
+    }
+
+    /// Reads a path from the RON file at `path`.
+    pub fn load(path: &Path) -> Result<DebugCameraPath, PersistError> {
+        load_ron(path)
+    }
+}
+
+impl DebugCameraSpline {
+    /// Writes this spline to the RON file at `path`.
+    pub fn save(&self, path: &Path) -> Result<(), PersistError> {
+        save_ron(self, path)
+    }
+
+    /// Reads a spline from the RON file at `path`.
+    pub fn load(path: &Path) -> Result<DebugCameraSpline, PersistError> {
+        load_ron(path)
+    }
+}
+
+/// Errors that can happen while saving or loading a [`DebugCameraState`], [`DebugCameraPath`] or
+/// [`DebugCameraSpline`].
+#[derive(Debug)]
+pub enum PersistError {
+    /// The file couldn't be read or written.
+    Io(io::Error),
+    /// The state couldn't be serialized.
+    Serialize(ron::Error),
+    /// The file doesn't contain a valid state.
+    Deserialize(ron::error::SpannedError),
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistError::Io(err) => write!(f, "could not access debug camera state: {err}"),
+            PersistError::Serialize(err) => {
+                write!(f, "could not serialize debug camera state: {err}")
+            }
+            PersistError::Deserialize(err) => write!(f, "invalid debug camera state: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PersistError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistError::Io(err) => Some(err),
+            PersistError::Serialize(err) => Some(err),
+            PersistError::Deserialize(err) => Some(err),
+        }
+    }
+}
+
+/// Writes `value` to the RON file at `path`.
+fn save_ron<T: Serialize>(value: &T, path: &Path) -> Result<(), PersistError> {
+    let ron = ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())?;
+    fs::write(path, ron)?;
+    Ok(())
+}
+
+/// Reads a value from the RON file at `path`.
+fn load_ron<T: DeserializeOwned>(path: &Path) -> Result<T, PersistError> {
+    let ron = fs::read_to_string(path)?;
+    Ok(ron::from_str(&ron)?)
+}
+
+impl From<io::Error> for PersistError {
+    fn from(err: io::Error) -> PersistError {
+        PersistError::Io(err)
+    }
+}
+
+impl From<ron::Error> for PersistError {
+    fn from(err: ron::Error) -> PersistError {
+        PersistError::Serialize(err)
+    }
+}
+
+impl From<ron::error::SpannedError> for PersistError {
+    fn from(err: ron::error::SpannedError) -> PersistError {
+        PersistError::Deserialize(err)
+    }
+}
+
+/// Saves and loads the state of debug cameras (see [`DebugCameraState`]) to a RON file, so that
+/// cameras keep their viewpoint and bookmarks between runs. Add this alongside
+/// [`crate::DebugCameraPlugin`]. Only available with the `persist` feature.
+#[derive(Debug, Default)]
+pub struct DebugCameraPersistPlugin {
+    pub persistence: DebugCameraPersistence,
+}
+
+impl Plugin for DebugCameraPersistPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DebugCameraPersist>()
+            .insert_resource(self.persistence.clone())
+            .add_systems(PostStartup, load_on_startup_system)
+            .add_systems(Update, persist_system)
+            .add_systems(Last, save_on_exit_system);
+    }
+}
+
+/// This system loads the saved state on startup, if enabled. It runs after startup so that cameras
+/// spawned in startup systems are already around.
+fn load_on_startup_system(
+    q: Query<&mut DebugCamera>,
+    bookmarks: ResMut<DebugCameraBookmarks>,
+    persistence: Res<DebugCameraPersistence>,
+) {
+    if persistence.load_on_startup && persistence.path.exists() {
+        load(q, bookmarks, &persistence);
+    }
+}
+
+/// This system handles [`DebugCameraPersist`] events.
+fn persist_system(
+    q: Query<&mut DebugCamera>,
+    bookmarks: ResMut<DebugCameraBookmarks>,
+    persistence: Res<DebugCameraPersistence>,
+    mut persist_evr: EventReader<DebugCameraPersist>,
+) {
+    // Only the last request matters, as saving and loading happen instantly
+    match persist_evr.read().last() {
+        Some(DebugCameraPersist::Save) => save(q.iter().next(), &bookmarks, &persistence),
+        Some(DebugCameraPersist::Load) => load(q, bookmarks, &persistence),
+        None => {}
+    }
+}
+
+/// This system saves the state when the app exits, if enabled.
+fn save_on_exit_system(
+    q: Query<&DebugCamera>,
+    bookmarks: Res<DebugCameraBookmarks>,
+    persistence: Res<DebugCameraPersistence>,
+    mut exit_evr: EventReader<AppExit>,
+) {
+    if exit_evr.read().count() > 0 && persistence.save_on_exit {
+        save(q.iter().next(), &bookmarks, &persistence);
+    }
+}
+
+/// Saves the state of `camera`, which should be the first debug camera found, logging any errors.
+fn save(
+    camera: Option<&DebugCamera>,
+    bookmarks: &DebugCameraBookmarks,
+    persistence: &DebugCameraPersistence,
+) {
+    let Some(camera) = camera else {
+        return;
+    };
+    let path = &persistence.path;
+    match DebugCameraState::capture(camera, bookmarks).save(path) {
+        Ok(()) => event!(
+            Level::INFO,
+            event = "debug_camera_state_saved",
+            path = %path.display(),
+        ),
+        Err(err) => event!(
+            Level::WARN,
+            event = "debug_camera_state_save_failed",
+            path = %path.display(),
+            error = %err,
+        ),
+    }
+}
+
+/// Loads the saved state into every debug camera, logging any errors. Invalid poses (see
+/// [`DebugCameraPose::is_valid`]), such as from hand-edited files, are logged and skipped, leaving
+/// the camera or bookmark as it was.
+fn load(
+    mut q: Query<&mut DebugCamera>,
+    mut bookmarks: ResMut<DebugCameraBookmarks>,
+    persistence: &DebugCameraPersistence,
+) {
+    let path = &persistence.path;
+    match DebugCameraState::load(path) {
+        Ok(state) => {
+            if state.pose.is_valid() {
+                for mut controlled_camera in q.iter_mut() {
+                    state.apply_to_camera(&mut controlled_camera);
+                }
+            } else {
+                event!(
+                    Level::WARN,
+                    event = "debug_camera_state_load_failed",
+                    path = %path.display(),
+                    error = "invalid camera pose",
+                    pose = ?state.pose,
+                );
+            }
+            for (slot, bookmark) in state.bookmarks.into_iter().enumerate() {
+                match bookmark {
+                    Some(pose) if !pose.is_valid() => event!(
+                        Level::WARN,
+                        event = "debug_camera_state_load_failed",
+                        path = %path.display(),
+                        error = "invalid bookmark pose",
+                        slot,
+                        pose = ?pose,
+                    ),
+                    bookmark => bookmarks.0[slot] = bookmark,
+                }
+            }
+        }
+        Err(err) => event!(
+            Level::WARN,
+            event = "debug_camera_state_load_failed",
+            path = %path.display(),
+            error = %err,
+        ),
+    }
+}
@@ -1,4 +1,6 @@
+use crate::components::DebugCameraPose;
 use bevy::prelude::*;
+use std::collections::VecDeque;
 
 #[derive(Resource, Default, Debug)]
 pub struct ActiveGamepad(pub Option<Gamepad>);
@@ -27,6 +29,428 @@ impl Default for DebugCameraActive {
     }
 }
 
+/// The entity currently selected for debug cameras to focus on, follow or look at with the
+/// corresponding bindings. This crate never sets this itself, so it should be kept up to date by
+/// your own selection or picking logic.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DebugCameraSelection(pub Option<Entity>);
+
+/// Number of bookmark slots in [`DebugCameraBookmarks`].
+pub const BOOKMARK_SLOTS: usize = 9;
+
+/// Camera poses saved with the bookmark bindings, which can be recalled later on. Slots are
+/// numbered from 0, so the first binding (`1` by default) uses slot 0. Bookmarks are shared between
+/// all debug cameras: saving takes the pose of the first camera found, and recalling moves every
+/// camera over its `transition_duration`.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct DebugCameraBookmarks(pub [Option<DebugCameraPose>; BOOKMARK_SLOTS]);
+
+impl DebugCameraBookmarks {
+    /// The pose saved in `slot`, if any.
+    pub fn get(&self, slot: usize) -> Option<DebugCameraPose> {
+        self.0.get(slot).copied().flatten()
+    }
+
+    /// Saves `pose` into `slot`, replacing any pose saved there. Does nothing if the slot doesn't
+    /// exist.
+    pub fn set(&mut self, slot: usize, pose: DebugCameraPose) {
+        if let Some(bookmark) = self.0.get_mut(slot) {
+            *bookmark = Some(pose);
+        }
+    }
+}
+
+/// A bounded history of significant poses of the debug camera, which can be navigated with the
+/// history bindings like a browser's back and forward buttons. Poses are recorded when a
+/// transition starts (such as when focusing on an entity or recalling a bookmark) or the camera
+/// jumps somewhere instantly (such as when teleported), and when the camera comes to rest for
+/// `settle_time` seconds. Like bookmarks, poses are taken from the first camera found, and
+/// navigating moves every camera.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DebugCameraHistory {
+    /// Largest number of poses kept. The oldest poses are dropped once this is exceeded.
+    pub capacity: usize,
+    /// Time (in seconds) the camera needs to stay still for its pose to be recorded.
+    pub settle_time: f32,
+    poses: VecDeque<DebugCameraPose>,
+    /// Index in `poses` of the pose we're currently at
+    current: usize,
+}
+
+impl DebugCameraHistory {
+    /// All recorded poses, from oldest to newest.
+    pub fn poses(&self) -> impl Iterator<Item = &DebugCameraPose> {
+        self.poses.iter()
+    }
+
+    /// The pose we're currently at in the history, if any.
+    pub fn current(&self) -> Option<DebugCameraPose> {
+        self.poses.get(self.current).copied()
+    }
+
+    /// Records `pose` as the current pose, dropping any poses ahead of the current one. Does
+    /// nothing if `pose` is (almost) the same as the current pose.
+    pub fn record(&mut self, pose: DebugCameraPose) {
+        if self
+            .current()
+            .is_some_and(|current| same_pose(&current, &pose))
+        {
+            return;
+        }
+        if !self.poses.is_empty() {
+            self.poses.truncate(self.current + 1);
+        }
+        self.poses.push_back(pose);
+        while self.poses.len() > self.capacity.max(1) {
+            self.poses.pop_front();
+        }
+        self.current = self.poses.len() - 1;
+    }
+
+    /// Steps back in the history, returning the pose to move to. `pose` is the camera's current
+    /// pose, which is recorded first so that stepping forward returns to it.
+    pub fn back(&mut self, pose: DebugCameraPose) -> Option<DebugCameraPose> {
+        self.record(pose);
+        self.current = self.current.checked_sub(1)?;
+        self.current()
+    }
+
+    /// Steps forward in the history, returning the pose to move to.
+    pub fn forward(&mut self) -> Option<DebugCameraPose> {
+        if self.current + 1 >= self.poses.len() {
+            return None;
+        }
+        self.current += 1;
+        self.current()
+    }
+
+    /// Forgets all recorded poses.
+    pub fn clear(&mut self) {
+        self.poses.clear();
+        self.current = 0;
+    }
+}
+
+impl Default for DebugCameraHistory {
+    fn default() -> DebugCameraHistory {
+        DebugCameraHistory {
+            capacity: 50,
+            settle_time: 1.,
+            poses: VecDeque::new(),
+            current: 0,
+        }
+    }
+}
+
+/// Whether two poses are close enough to be considered the same place in [`DebugCameraHistory`].
+pub(crate) fn same_pose(a: &DebugCameraPose, b: &DebugCameraPose) -> bool {
+    a.position.abs_diff_eq(b.position, 1e-3)
+        && a.fwd.abs_diff_eq(b.fwd, 1e-4)
+        && a.up.abs_diff_eq(b.up, 1e-4)
+}
+
+/// A pose of the camera at a point in time along a [`DebugCameraPath`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebugCameraPathSample {
+    /// Time (in seconds) since the start of the path.
+    pub time: f32,
+    pub pose: DebugCameraPose,
+}
+
+/// A recorded flight path of a camera, as a timeline of poses. With the `persist` feature, paths
+/// can also be saved to and loaded from RON files.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebugCameraPath {
+    /// The samples making up the path, ordered by time.
+    pub samples: Vec<DebugCameraPathSample>,
+}
+
+impl DebugCameraPath {
+    /// Length of the path in seconds.
+    pub fn duration(&self) -> f32 {
+        self.samples.last().map_or(0., |sample| sample.time)
+    }
+
+    /// The pose at `time` seconds into the path, interpolating between the samples around it.
+    /// Times outside the path are clamped to its ends. Returns `None` if the path is empty.
+    pub fn sample(&self, time: f32) -> Option<DebugCameraPose> {
+        let next = self.samples.partition_point(|sample| sample.time <= time);
+        let after = self.samples.get(next);
+        let Some(before) = next.checked_sub(1).and_then(|i| self.samples.get(i)) else {
+            return after.map(|sample| sample.pose);
+        };
+        match after {
+            Some(after) if after.time > before.time => {
+                let t = (time - before.time) / (after.time - before.time);
+                Some(before.pose.lerp(&after.pose, t))
+            }
+            _ => Some(before.pose),
+        }
+    }
+}
+
+/// Whether the [`DebugCameraPathRecorder`] is recording or playing back its path.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DebugCameraPathState {
+    #[default]
+    Idle,
+    /// Recording the camera's pose, `time` seconds into the path.
+    Recording { time: f32 },
+    /// Moving cameras along the path, `time` seconds into it.
+    Playing { time: f32 },
+}
+
+/// Records the debug camera's flight path and plays it back. Recording samples the pose of the
+/// first camera found, and playback moves every camera. Playback follows the recorded timeline
+/// rather than individual frames, so the camera goes through exactly the same poses at the same
+/// times regardless of frame rate.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DebugCameraPathRecorder {
+    /// The recorded path, which is also the one that gets played back.
+    pub path: DebugCameraPath,
+    pub state: DebugCameraPathState,
+    /// Time (in seconds) between samples while recording. Set to 0 to record every frame.
+    pub interval: f32,
+    /// Playback speed, where 1 plays the path back in real time.
+    pub speed: f32,
+    /// If true, playback starts over once it reaches the end of the path. Otherwise, it stops and
+    /// leaves the camera at the end of the path.
+    pub looping: bool,
+}
+
+impl DebugCameraPathRecorder {
+    /// Starts recording a new path, discarding the current one.
+    pub fn record(&mut self) {
+        self.path.samples.clear();
+        self.state = DebugCameraPathState::Recording { time: 0. };
+    }
+
+    /// Starts playing back the path from the beginning. Does nothing if the path is empty.
+    pub fn play(&mut self) {
+        if !self.path.samples.is_empty() {
+            self.state = DebugCameraPathState::Playing { time: 0. };
+        }
+    }
+
+    /// Stops recording or playing back.
+    pub fn stop(&mut self) {
+        self.state = DebugCameraPathState::Idle;
+    }
+}
+
+impl Default for DebugCameraPathRecorder {
+    fn default() -> DebugCameraPathRecorder {
+        DebugCameraPathRecorder {
+            path: DebugCameraPath::default(),
+            state: DebugCameraPathState::Idle,
+            interval: 0.,
+            speed: 1.,
+            looping: false,
+        }
+    }
+}
+
+/// A keyframe of a [`DebugCameraSpline`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebugCameraKeyframe {
+    pub pose: DebugCameraPose,
+    /// Time (in seconds) it takes to reach this keyframe from the previous one. For the first
+    /// keyframe, this is only used by closed splines, to come back from the last keyframe.
+    pub duration: f32,
+}
+
+/// A smooth camera path through a list of keyframes. Positions follow a Catmull-Rom spline through
+/// the keyframes, and orientations are interpolated with a spherical interpolation between them.
+/// With the `persist` feature, splines can also be saved to and loaded from RON files.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct DebugCameraSpline {
+    pub keyframes: Vec<DebugCameraKeyframe>,
+    /// If true, the spline continues from the last keyframe back to the first one, and playback
+    /// loops.
+    pub closed: bool,
+}
+
+impl DebugCameraSpline {
+    /// Number of segments between keyframes.
+    fn segment_count(&self) -> usize {
+        match self.keyframes.len() {
+            0 | 1 => 0,
+            n if self.closed => n,
+            n => n - 1,
+        }
+    }
+
+    /// The keyframe at `index`, wrapping around closed splines and clamping to the ends of open
+    /// ones.
+    fn keyframe(&self, index: isize) -> &DebugCameraKeyframe {
+        let n = self.keyframes.len() as isize;
+        let index = if self.closed {
+            index.rem_euclid(n)
+        } else {
+            index.clamp(0, n - 1)
+        };
+        &self.keyframes[index as usize]
+    }
+
+    /// Length of the spline in seconds.
+    pub fn duration(&self) -> f32 {
+        (0..self.segment_count())
+            .map(|i| self.keyframe(i as isize + 1).duration.max(0.))
+            .sum()
+    }
+
+    /// The pose at `time` seconds along the spline. Times outside the spline wrap around closed
+    /// splines, and are clamped to the ends of open ones. Returns `None` if there are no keyframes.
+    pub fn sample(&self, time: f32) -> Option<DebugCameraPose> {
+        let first = self.keyframes.first()?;
+        let duration = self.duration();
+        if self.segment_count() == 0 || duration <= 0. {
+            return Some(first.pose);
+        }
+        let mut time = if self.closed {
+            time.rem_euclid(duration)
+        } else {
+            time.clamp(0., duration)
+        };
+
+        // Find the segment we're in, and how far along it
+        let mut segment = 0;
+        let mut t = 1.;
+        for i in 0..self.segment_count() {
+            segment = i as isize;
+            let segment_duration = self.keyframe(segment + 1).duration.max(0.);
+            if time <= segment_duration {
+                t = if segment_duration > 0. {
+                    time / segment_duration
+                } else {
+                    1.
+                };
+                break;
+            }
+            time -= segment_duration;
+        }
+
+        let [p0, p1, p2, p3] =
+            [-1, 0, 1, 2].map(|offset| self.keyframe(segment + offset).pose.position);
+        let position = 0.5
+            * (2. * p1
+                + (p2 - p0) * t
+                + (2. * p0 - 5. * p1 + 4. * p2 - p3) * t * t
+                + (3. * p1 - p0 - 3. * p2 + p3) * t * t * t);
+        let rotation = self
+            .keyframe(segment)
+            .pose
+            .rotation()
+            .slerp(self.keyframe(segment + 1).pose.rotation(), t);
+        Some(DebugCameraPose::from_rotation(position, rotation))
+    }
+}
+
+/// Keyframes dropped with the keyframe bindings, and the state of their flythrough. Keyframes are
+/// taken from the pose of the first camera found, and the flythrough moves every camera.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DebugCameraFlythrough {
+    pub spline: DebugCameraSpline,
+    /// Time (in seconds) between new keyframes and the previous ones. The timing of existing
+    /// keyframes can be changed through [`DebugCameraFlythrough::spline`].
+    pub segment_duration: f32,
+    /// Time (in seconds) into the flythrough while it's playing.
+    pub time: Option<f32>,
+}
+
+impl DebugCameraFlythrough {
+    /// Adds a keyframe at `pose` to the end of the spline.
+    pub fn add_keyframe(&mut self, pose: DebugCameraPose) {
+        self.spline.keyframes.push(DebugCameraKeyframe {
+            pose,
+            duration: self.segment_duration,
+        });
+    }
+
+    /// Starts playing the flythrough from the beginning. Does nothing without keyframes.
+    pub fn play(&mut self) {
+        if !self.spline.keyframes.is_empty() {
+            self.time = Some(0.);
+        }
+    }
+
+    /// Stops playing the flythrough.
+    pub fn stop(&mut self) {
+        self.time = None;
+    }
+}
+
+impl Default for DebugCameraFlythrough {
+    fn default() -> DebugCameraFlythrough {
+        DebugCameraFlythrough {
+            spline: DebugCameraSpline::default(),
+            segment_duration: 2.,
+            time: None,
+        }
+    }
+}
+
+/// Overrides for the near and far clip planes of every debug camera's [`Projection`]. The clip
+/// plane bindings adjust these, but they can also be set directly at runtime. Planes left as `None`
+/// keep the projection's own value, and clearing an override restores the value the projection had
+/// before it was applied.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DebugCameraClipPlanes {
+    /// Distance to the near clip plane. Pushing this out lets cameras see through nearby walls.
+    pub near: Option<f32>,
+    /// Distance to the far clip plane.
+    pub far: Option<f32>,
+    /// Lowest value the near plane can be set to through [`DebugCameraClipPlanes::set_near`].
+    pub near_min: f32,
+    /// Highest value the far plane can be set to through [`DebugCameraClipPlanes::set_far`].
+    pub far_max: f32,
+    /// Smallest distance kept between the near and far planes, so that adjusting one of them can
+    /// never push it past the other.
+    pub min_gap: f32,
+    /// Rate (in natural log units per second) at which the planes move while their bindings are
+    /// held.
+    pub rate: f32,
+}
+
+impl DebugCameraClipPlanes {
+    /// Sets the near plane, keeping it above `near_min` and at least `min_gap` in front of the far
+    /// plane (or `far_max` if it isn't set).
+    pub fn set_near(&mut self, near: f32) {
+        let max = self.far.unwrap_or(self.far_max) - self.min_gap;
+        self.near = Some(near.min(max).max(self.near_min));
+    }
+
+    /// Sets the far plane, keeping it below `far_max` and at least `min_gap` behind the near plane
+    /// (or `near_min` if it isn't set).
+    pub fn set_far(&mut self, far: f32) {
+        let min = self.near.unwrap_or(self.near_min) + self.min_gap;
+        self.far = Some(far.max(min).min(self.far_max));
+    }
+
+    /// Clears both overrides, restoring each projection's original planes.
+    pub fn reset(&mut self) {
+        self.near = None;
+        self.far = None;
+    }
+}
+
+impl Default for DebugCameraClipPlanes {
+    fn default() -> DebugCameraClipPlanes {
+        DebugCameraClipPlanes {
+            near: None,
+            far: None,
+            near_min: 0.001,
+            far_max: 100_000.,
+            min_gap: 0.01,
+            rate: 1.5,
+        }
+    }
+}
+
 /// Configurable bindings for keyboard input. Field defaults can be found in the crate root
 /// documentation.
 #[derive(Resource, Debug, Clone)]
@@ -40,6 +464,48 @@ pub struct KeyboardBindings {
     pub right: KeyCode,
     pub roll_left: KeyCode,
     pub roll_right: KeyCode,
+    pub boost: KeyCode,
+    pub precision: KeyCode,
+    pub jump: KeyCode,
+    pub crouch: KeyCode,
+    pub focus: KeyCode,
+    pub follow: KeyCode,
+    pub look_at: KeyCode,
+    pub projection: KeyCode,
+    pub view_front: KeyCode,
+    pub view_side: KeyCode,
+    pub view_top: KeyCode,
+    /// Key that can be held alongside the view bindings to look from the opposite side instead
+    /// (back, left and bottom respectively).
+    pub view_opposite: KeyCode,
+    /// Key that must be held while scrolling to dolly zoom.
+    pub dolly_zoom: KeyCode,
+    pub near_plane_out: KeyCode,
+    pub near_plane_in: KeyCode,
+    pub far_plane_out: KeyCode,
+    pub far_plane_in: KeyCode,
+    pub clip_reset: KeyCode,
+    /// Keys for each of the [`DebugCameraBookmarks`] slots. Pressing one recalls the pose saved in
+    /// that slot.
+    pub bookmarks: [KeyCode; BOOKMARK_SLOTS],
+    /// Key that must be held alongside a bookmark key to save the current pose into it.
+    pub bookmark_save: KeyCode,
+    /// Key that logs the current pose as a string that can be shared and parsed back. See
+    /// [`DebugCameraPose`]'s `Display` implementation.
+    pub copy_pose: KeyCode,
+    pub history_back: KeyCode,
+    pub history_forward: KeyCode,
+    /// Key that starts recording a new path with the [`DebugCameraPathRecorder`], or stops the
+    /// current recording.
+    pub record_path: KeyCode,
+    /// Key that starts or stops playing back the recorded path.
+    pub play_path: KeyCode,
+    /// Key that adds a keyframe at the current pose to the [`DebugCameraFlythrough`].
+    pub keyframe_add: KeyCode,
+    /// Key that removes the last keyframe of the flythrough.
+    pub keyframe_remove: KeyCode,
+    /// Key that starts or stops playing the flythrough.
+    pub play_flythrough: KeyCode,
 }
 
 impl Default for KeyboardBindings {
@@ -54,6 +520,89 @@ impl Default for KeyboardBindings {
             right: KeyCode::D,
             roll_left: KeyCode::Q,
             roll_right: KeyCode::E,
+            boost: KeyCode::ControlLeft,
+            precision: KeyCode::AltLeft,
+            jump: KeyCode::Space,
+            crouch: KeyCode::C,
+            focus: KeyCode::F,
+            follow: KeyCode::T,
+            look_at: KeyCode::L,
+            projection: KeyCode::Numpad5,
+            view_front: KeyCode::Numpad1,
+            view_side: KeyCode::Numpad3,
+            view_top: KeyCode::Numpad7,
+            view_opposite: KeyCode::ControlLeft,
+            dolly_zoom: KeyCode::Z,
+            near_plane_out: KeyCode::BracketRight,
+            near_plane_in: KeyCode::BracketLeft,
+            far_plane_out: KeyCode::Equals,
+            far_plane_in: KeyCode::Minus,
+            clip_reset: KeyCode::Backslash,
+            bookmarks: [
+                KeyCode::Key1,
+                KeyCode::Key2,
+                KeyCode::Key3,
+                KeyCode::Key4,
+                KeyCode::Key5,
+                KeyCode::Key6,
+                KeyCode::Key7,
+                KeyCode::Key8,
+                KeyCode::Key9,
+            ],
+            bookmark_save: KeyCode::ControlLeft,
+            copy_pose: KeyCode::P,
+            history_back: KeyCode::Comma,
+            history_forward: KeyCode::Period,
+            record_path: KeyCode::F9,
+            play_path: KeyCode::F10,
+            keyframe_add: KeyCode::K,
+            keyframe_remove: KeyCode::Back,
+            play_flythrough: KeyCode::F8,
+        }
+    }
+}
+
+/// Selects how the mouse drives debug cameras. Only affects keyboard + mouse input.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationScheme {
+    /// Classic flycam controls. The cursor is locked to the window and the mouse always rotates
+    /// the camera.
+    #[default]
+    Fly,
+    /// Editor-style controls, similar to Blender and Unity. The cursor is left free, and the mouse
+    /// only moves the camera while one of the [`MouseBindings`] buttons is held down. Holding the
+    /// look button enables the regular fly controls, the orbit button orbits around the camera's
+    /// focus point, and the pan button (with its modifier) pans the view. The mouse wheel dollies
+    /// towards and away from the focus point.
+    Editor,
+}
+
+/// Configurable bindings for the mouse buttons used by the [`NavigationScheme::Editor`] scheme, and
+/// by the [`crate::DebugCameraMode::Arcball`] and [`crate::DebugCameraMode::Ortho2d`] modes. Field
+/// defaults can be found in the crate root documentation.
+#[derive(Resource, Debug, Clone)]
+pub struct MouseBindings {
+    pub look: MouseButton,
+    pub orbit: MouseButton,
+    pub pan: MouseButton,
+    /// Key that must be held alongside `pan` to pan the view. This lets `pan` and `orbit` share
+    /// the same button. 2D cameras don't orbit, so they pan without it.
+    pub pan_modifier: KeyCode,
+    pub arcball: MouseButton,
+    /// Button that zooms in by narrowing the field of view while held. This is only used with the
+    /// [`NavigationScheme::Fly`] scheme, as the editor scheme needs the mouse buttons to navigate.
+    pub fov_zoom: MouseButton,
+}
+
+impl Default for MouseBindings {
+    fn default() -> MouseBindings {
+        MouseBindings {
+            look: MouseButton::Right,
+            orbit: MouseButton::Middle,
+            pan: MouseButton::Middle,
+            pan_modifier: KeyCode::ShiftLeft,
+            arcball: MouseButton::Left,
+            fov_zoom: MouseButton::Right,
         }
     }
 }
@@ -71,6 +620,24 @@ pub struct GamepadBindings {
     pub roll_right: GamepadButtonType,
     pub yaw: GamepadAxisType,
     pub pitch: GamepadAxisType,
+    pub speed_up: GamepadButtonType,
+    pub speed_down: GamepadButtonType,
+    pub boost: GamepadButtonType,
+    pub precision: GamepadButtonType,
+    pub jump: GamepadButtonType,
+    pub crouch: GamepadButtonType,
+    pub focus: GamepadButtonType,
+    pub follow: GamepadButtonType,
+    pub look_at: GamepadButtonType,
+    pub projection: GamepadButtonType,
+    /// Button that must be held alongside the clip plane bindings. While it's held, the speed, look
+    /// at and projection bindings are ignored, so that they can share the same buttons.
+    pub clip_modifier: GamepadButtonType,
+    pub near_plane_out: GamepadButtonType,
+    pub near_plane_in: GamepadButtonType,
+    pub far_plane_out: GamepadButtonType,
+    pub far_plane_in: GamepadButtonType,
+    pub clip_reset: GamepadButtonType,
 }
 
 impl Default for GamepadBindings {
@@ -85,6 +652,52 @@ impl Default for GamepadBindings {
             roll_right: GamepadButtonType::RightTrigger,
             yaw: GamepadAxisType::RightStickX,
             pitch: GamepadAxisType::RightStickY,
+            speed_up: GamepadButtonType::DPadUp,
+            speed_down: GamepadButtonType::DPadDown,
+            boost: GamepadButtonType::LeftThumb,
+            precision: GamepadButtonType::RightThumb,
+            jump: GamepadButtonType::South,
+            crouch: GamepadButtonType::East,
+            focus: GamepadButtonType::North,
+            follow: GamepadButtonType::West,
+            look_at: GamepadButtonType::DPadRight,
+            projection: GamepadButtonType::DPadLeft,
+            clip_modifier: GamepadButtonType::Select,
+            near_plane_out: GamepadButtonType::DPadUp,
+            near_plane_in: GamepadButtonType::DPadDown,
+            far_plane_out: GamepadButtonType::DPadRight,
+            far_plane_in: GamepadButtonType::DPadLeft,
+            clip_reset: GamepadButtonType::Start,
         }
     }
 }
+
+/// Finds the height of the ground for cameras in [`crate::DebugCameraMode::Walk`]. Implement this
+/// on top of your own raycaster, heightmap or physics engine, and insert it into the app with the
+/// [`DebugCameraGround`] resource.
+pub trait GroundQuery: Send + Sync + 'static {
+    /// Returns the height (y coordinate) of the ground directly below or above `position`, or
+    /// `None` if there is no ground there, in which case the camera will fall.
+    fn ground_height(&self, position: Vec3) -> Option<f32>;
+}
+
+/// A flat, infinite ground plane at a fixed height.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FlatGround(pub f32);
+
+impl GroundQuery for FlatGround {
+    fn ground_height(&self, _position: Vec3) -> Option<f32> {
+        Some(self.0)
+    }
+}
+
+/// The ground used by walking debug cameras. If this resource doesn't exist, cameras walk on a
+/// flat plane at y = 0.
+#[derive(Resource)]
+pub struct DebugCameraGround(pub Box<dyn GroundQuery>);
+
+impl DebugCameraGround {
+    pub fn new(query: impl GroundQuery) -> DebugCameraGround {
+        DebugCameraGround(Box::new(query))
+    }
+}
@@ -1,173 +1,1627 @@
 use crate::{
-    components::DebugCamera,
-    resources::{ActiveGamepad, DebugCameraActive, GamepadBindings, KeyboardBindings},
+    components::{
+        DebugCamera, DebugCameraBounds, DebugCameraCollider, DebugCameraConstraints,
+        DebugCameraLookAt, DebugCameraMode, DebugCameraPose, MIN_FOCUS_DISTANCE,
+    },
+    events::{DebugCameraAction, DebugCameraCommand, DebugCameraFocus},
+    resources::{
+        same_pose, ActiveGamepad, DebugCameraActive, DebugCameraBookmarks, DebugCameraClipPlanes,
+        DebugCameraFlythrough, DebugCameraGround, DebugCameraHistory, DebugCameraPathRecorder,
+        DebugCameraPathSample, DebugCameraPathState, DebugCameraSelection, GamepadBindings,
+        KeyboardBindings, MouseBindings, NavigationScheme,
+    },
 };
 use bevy::{
     input::{
         gamepad::{GamepadButton, GamepadConnection, GamepadEvent, GamepadSettings},
-        mouse::MouseMotion,
+        mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
     },
     prelude::*,
+    render::{camera::ScalingMode, primitives::Aabb},
     utils::tracing::{event, Level},
-    window::CursorGrabMode,
+    utils::HashMap,
+    window::{CursorGrabMode, PrimaryWindow},
 };
 
-/// This is the main system responsible for updating camera movement. It takes mouse, keyboard, and
-/// gamepad input and updates the [`DebugCamera`] component acording to those changes. This
-/// explicitly does *not* update the camera's tranform.
+/// Rate (in natural log units per second) at which the orbit radius changes when holding the up or
+/// down bindings at full strength.
+const ORBIT_ZOOM_RATE: f32 = 1.5;
+/// Fraction by which a single line of mouse wheel scrolling changes the orbit radius.
+const ORBIT_SCROLL_STEP: f32 = 0.1;
+/// Number of pixels we treat as a single line for mouse wheels and touchpads that report scrolling
+/// in pixels.
+const PIXELS_PER_SCROLL_LINE: f32 = 16.;
+/// Distance the camera pans per pixel of mouse motion with the editor navigation scheme, as a
+/// fraction of its focus distance. Scaling by the focus distance keeps the focus point roughly
+/// under the cursor.
+const EDITOR_PAN_PER_PIXEL: f32 = 0.0015;
+/// Largest angle (in radians) a horizon-locked camera can pitch above or below the horizon. Kept
+/// just shy of straight up or down so the camera never flips over the poles.
+const HORIZON_LOCK_MAX_PITCH: f32 = 89. * std::f32::consts::PI / 180.;
+/// Rate (in zoom levels per second) at which top-down cameras zoom when holding the up or down
+/// bindings at full strength. See [`crate::DebugCameraTopDown::zoom`].
+const TOP_DOWN_ZOOM_RATE: f32 = 0.5;
+/// Change in zoom level for a single line of mouse wheel scrolling in top-down cameras.
+const TOP_DOWN_SCROLL_STEP: f32 = 0.05;
+/// Narrowest field of view (in radians) dolly zooming can reach.
+const DOLLY_ZOOM_MIN_FOV: f32 = 5. * std::f32::consts::PI / 180.;
+/// Widest field of view (in radians) dolly zooming can reach.
+const DOLLY_ZOOM_MAX_FOV: f32 = 120. * std::f32::consts::PI / 180.;
+/// Radius we assume entities without an [`Aabb`] have when focusing on them.
+const DEFAULT_FOCUS_RADIUS: f32 = 0.5;
+/// Extra room left around entities when focusing on them, as a multiple of their radius.
+const FOCUS_MARGIN: f32 = 1.1;
+
+/// This system applies [`DebugCameraCommand`]s to cameras. It runs before
+/// [`camera_movement_system`], so commands take effect on the same frame they're sent. Teleports
+/// to invalid poses (see [`DebugCameraPose::is_valid`]) are ignored and logged.
+pub fn camera_command_system(
+    mut q: Query<(Entity, &mut DebugCamera)>,
+    mut command_evr: EventReader<DebugCameraCommand>,
+) {
+    for command in command_evr.read() {
+        // Poses with a degenerate orientation would leave cameras with NaNs in their transform
+        if let DebugCameraAction::Teleport { pose, .. } = command.action {
+            if !pose.is_valid() {
+                event!(
+                    Level::WARN,
+                    event = "debug_camera_invalid_pose",
+                    pose = ?pose,
+                );
+                continue;
+            }
+        }
+        for (entity, mut controlled_camera) in q.iter_mut() {
+            if command.camera.is_some_and(|camera| camera != entity) {
+                continue;
+            }
+            match command.action {
+                DebugCameraAction::Teleport { pose, smooth } => {
+                    if smooth {
+                        let duration = controlled_camera.transition_duration;
+                        controlled_camera.transition_to(pose, duration);
+                    } else {
+                        controlled_camera.set_pose(pose);
+                        orthonormalize(&mut controlled_camera);
+                    }
+                }
+                DebugCameraAction::LookAt(point) => {
+                    controlled_camera.mark_jump();
+                    controlled_camera.set_focus(point);
+                    orthonormalize(&mut controlled_camera);
+                }
+                DebugCameraAction::SetSpeed(speed) => {
+                    controlled_camera.speed_translate = speed.clamp(
+                        controlled_camera.speed_translate_min,
+                        controlled_camera.speed_translate_max,
+                    );
+                }
+                DebugCameraAction::SetMode(mode) => {
+                    controlled_camera.mode = mode;
+                }
+            }
+        }
+    }
+}
+
+/// This is the main system responsible for updating camera movement. It takes mouse, keyboard, and
+/// gamepad input and updates the [`DebugCamera`] component acording to those changes. This
+/// explicitly does *not* update the camera's tranform.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_movement_system(
+    mut q: Query<&mut DebugCamera>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    mut debug_camera_active: ResMut<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    mouse_bindings: Res<MouseBindings>,
+    navigation_scheme: Res<NavigationScheme>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut motion_evr: EventReader<MouseMotion>,
+    mut wheel_evr: EventReader<MouseWheel>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<Input<GamepadButton>>,
+    button_axes: Res<Axis<GamepadButton>>,
+    active_gamepad: ResMut<ActiveGamepad>,
+) {
+    // Shortcut if neither control scheme is active. This is not strictly needed, but it avoids
+    // some computation if controls are inactive.
+    if !(debug_camera_active.gamepad || debug_camera_active.keymouse) {
+        return;
+    }
+
+    if debug_camera_active.gamepad {
+        if let Some(gamepad) = active_gamepad.0 {
+            if button_axes
+                .get(GamepadButton::new(gamepad, gamepad_bindings.esc))
+                .is_some()
+            {
+                debug_camera_active.esc_toggled = !debug_camera_active.esc_toggled;
+                return;
+            }
+        }
+    }
+
+    if debug_camera_active.keymouse && keys.just_released(keyboard_bindings.esc) {
+        debug_camera_active.esc_toggled = !debug_camera_active.esc_toggled;
+        return;
+    }
+
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    // All calculations before going into each camera are done from the camera's frame
+    // of reference. We assume x = fwd, y = right, z = up
+    let mut input = MovementInput::default();
+    // Mouse motion used to look around, in pixels. Unlike the other inputs, this is not scaled by
+    // time, as the mouse already reports how far it moved this frame.
+    let mut look_delta = Vec2::default();
+    // Mouse wheel input, in lines, while using the fly controls. This changes the translation speed
+    // or orbit radius depending on the camera's mode.
+    let mut scroll = 0.;
+    // Number of steps by which to increase (or decrease, if negative) the translation speed,
+    // regardless of the camera's mode.
+    let mut speed_steps = 0.;
+    // Mouse input for the editor navigation scheme. These orbit around, pan and dolly relative to
+    // the focus point, regardless of the camera's mode.
+    let mut orbit_delta = Vec2::default();
+    let mut pan_delta = Vec2::default();
+    let mut dolly_scroll = 0.;
+
+    // First, apply controller if present and active
+    if debug_camera_active.gamepad {
+        if let Some(gamepad) = active_gamepad.0 {
+            // Apply translation, rotation and speed modifiers
+            input += gamepad_movement_input(
+                gamepad,
+                time.delta_seconds(),
+                &gamepad_bindings,
+                &axes,
+                &buttons,
+                &button_axes,
+            );
+
+            // Apply speed changes, unless the buttons are being used to adjust the clip planes
+            if !buttons.pressed(GamepadButton::new(gamepad, gamepad_bindings.clip_modifier)) {
+                speed_steps += buttons_to_dir(
+                    buttons.just_pressed(GamepadButton::new(gamepad, gamepad_bindings.speed_up)),
+                    buttons.just_pressed(GamepadButton::new(gamepad, gamepad_bindings.speed_down)),
+                );
+            }
+        }
+    }
+
+    // Next, apply keyboard and mouse controls
+    if debug_camera_active.keymouse {
+        let keyboard = keyboard_movement_input(time.delta_seconds(), &keyboard_bindings, &keys);
+        // The speed modifiers apply to all mouse controls, not just the fly controls
+        input.boost |= keyboard.boost;
+        input.precision |= keyboard.precision;
+        let mouse_delta = {
+            let mut d = Vec2::default();
+            for ev in motion_evr.read() {
+                d -= ev.delta;
+            }
+            d
+        };
+        let wheel = wheel_evr.read().map(scroll_lines).sum::<f32>();
+        // Scrolling is handled by `camera_fov_system` instead while zooming
+        let wheel = if fov_zoom_scrolling(&navigation_scheme, &mouse_buttons, &mouse_bindings)
+            || keys.pressed(keyboard_bindings.dolly_zoom)
+        {
+            0.
+        } else {
+            wheel
+        };
+
+        // With the editor scheme, the fly controls are only active while the look button is held
+        // down. Otherwise, the mouse is used to orbit and pan.
+        let fly_controls = match *navigation_scheme {
+            NavigationScheme::Fly => true,
+            NavigationScheme::Editor => mouse_buttons.pressed(mouse_bindings.look),
+        };
+
+        if fly_controls {
+            input += keyboard;
+            look_delta += mouse_delta;
+            scroll += wheel;
+        } else if mouse_buttons.pressed(mouse_bindings.pan)
+            && keys.pressed(mouse_bindings.pan_modifier)
+        {
+            pan_delta += mouse_delta;
+        } else if mouse_buttons.pressed(mouse_bindings.orbit) {
+            orbit_delta += mouse_delta;
+        }
+        if !fly_controls {
+            dolly_scroll += wheel;
+        }
+    }
+
+    for mut controlled_camera in q.iter_mut() {
+        let right = orthonormalize(&mut controlled_camera);
+
+        // This matrix converts the local coordinate frame to world coordinates. General assumption
+        // is that the up and fwd vectors are unit vectors and perpendicular to each other at this
+        // point.
+        let basis_matrix = Mat3::from_cols(controlled_camera.fwd, controlled_camera.up, right);
+        let mode_speed_steps = match controlled_camera.mode {
+            DebugCameraMode::Fly | DebugCameraMode::Walk => speed_steps + scroll,
+            DebugCameraMode::Orbit
+            | DebugCameraMode::Arcball
+            | DebugCameraMode::Follow(_)
+            | DebugCameraMode::TopDown
+            | DebugCameraMode::Ortho2d => speed_steps,
+        };
+        change_speed(&mut controlled_camera, mode_speed_steps);
+        let speed_translate = controlled_camera.speed_translate;
+
+        // Modifiers scale both translation and rotation for this camera
+        let modifier = speed_modifier(&controlled_camera, input.boost, input.precision);
+        let local_translate_vec = modifier * input.translate;
+        let rotate_vec = modifier * input.rotate;
+        let mouse_sensitivity =
+            modifier * controlled_camera.mouse_sensitivity / controlled_camera.fov_zoom.current;
+
+        // Work out this frame's translation (in world space) and rotation (in radians) according to
+        // the camera's mode, and smooth them out if the camera has inertia.
+        let translation = match controlled_camera.mode {
+            // Translation is just a simple basis matrix multiplication
+            DebugCameraMode::Fly => speed_translate * (basis_matrix * local_translate_vec),
+            // Panning moves the camera along its fwd/right plane, taking the focus point with it.
+            // Up and down are reserved for changing the orbit radius.
+            DebugCameraMode::Orbit | DebugCameraMode::Arcball => {
+                let pan = Vec3::new(local_translate_vec.x, 0., local_translate_vec.z);
+                speed_translate * (basis_matrix * pan)
+            }
+            // Walking moves along the horizontal plane. Vertical motion comes from gravity and
+            // jumping, which are applied to the camera's velocity by `camera_walk_system`, so we
+            // just carry that velocity over.
+            DebugCameraMode::Walk => {
+                let heading = Vec3::new(controlled_camera.fwd.x, 0., controlled_camera.fwd.z)
+                    .normalize_or_zero();
+                let walk = heading * local_translate_vec.x
+                    + heading.cross(Vec3::Y) * local_translate_vec.z;
+                speed_translate * walk
+                    + Vec3::Y * controlled_camera.velocity.y * time.delta_seconds()
+            }
+            // Following, top-down and 2D cameras are moved entirely by their own systems
+            DebugCameraMode::Follow(_) | DebugCameraMode::TopDown | DebugCameraMode::Ortho2d => {
+                Vec3::ZERO
+            }
+        };
+        // Arcball cameras are rotated by dragging the mouse in `camera_arcball_system` instead,
+        // while top-down and 2D cameras leave the mouse free for edge scrolling and dragging
+        let look_delta = match controlled_camera.mode {
+            DebugCameraMode::Arcball | DebugCameraMode::TopDown | DebugCameraMode::Ortho2d => {
+                Vec2::ZERO
+            }
+            _ => look_delta,
+        };
+        let rotation =
+            controlled_camera.speed_rotate * rotate_vec + mouse_sensitivity * look_delta.extend(0.);
+        let (translation, rotation) = apply_inertia(
+            &mut controlled_camera,
+            translation,
+            rotation,
+            time.delta_seconds(),
+        );
+
+        // Translation first, rotation last
+        controlled_camera.position += translation;
+        match controlled_camera.mode {
+            DebugCameraMode::Fly | DebugCameraMode::Walk => {
+                rotate(&mut controlled_camera, right, rotation)
+            }
+            DebugCameraMode::Orbit | DebugCameraMode::Arcball => {
+                orbit(&mut controlled_camera, right, rotation);
+                let zoom = ORBIT_ZOOM_RATE * local_translate_vec.y + ORBIT_SCROLL_STEP * scroll;
+                zoom_to_focus(&mut controlled_camera, zoom);
+            }
+            DebugCameraMode::Follow(_) | DebugCameraMode::TopDown | DebugCameraMode::Ortho2d => {}
+        }
+
+        // 2D cameras have their own mouse controls, and orbiting would tip them out of their plane
+        if *navigation_scheme == NavigationScheme::Editor
+            && controlled_camera.mode != DebugCameraMode::Ortho2d
+        {
+            // The basis may have changed while applying the camera's mode
+            let right = controlled_camera.fwd.cross(controlled_camera.up);
+            orbit(
+                &mut controlled_camera,
+                right,
+                mouse_sensitivity * orbit_delta.extend(0.),
+            );
+
+            // Mouse deltas are in screen space, where y points down. We move the camera against
+            // the drag direction so the scene follows the cursor.
+            let pan_scale = EDITOR_PAN_PER_PIXEL * controlled_camera.focus_distance;
+            let pan = pan_scale * (right * pan_delta.x - controlled_camera.up * pan_delta.y);
+            controlled_camera.position += pan;
+            zoom_to_focus(&mut controlled_camera, ORBIT_SCROLL_STEP * dolly_scroll);
+        }
+    }
+}
+
+/// This system rotates cameras in [`DebugCameraMode::Arcball`] around their focus point while the
+/// arcball mouse button is held down. The cursor is projected onto a virtual sphere filling the
+/// window, and the camera rotates as if that sphere was being rolled by the cursor. Dragging around
+/// the edge of the window rolls the camera.
+pub fn camera_arcball_system(
+    mut q: Query<&mut DebugCamera>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mouse_bindings: Res<MouseBindings>,
+    debug_camera_active: Res<DebugCameraActive>,
+    mut last_cursor: Local<Option<Vec3>>,
+) {
+    // The cursor's position on the arcball, if it's being dragged
+    let cursor = match windows.get_single() {
+        Ok(window)
+            if debug_camera_active.keymouse
+                && !debug_camera_active.esc_toggled
+                && mouse_buttons.pressed(mouse_bindings.arcball) =>
+        {
+            window
+                .cursor_position()
+                .map(|cursor| arcball_point(cursor, window))
+        }
+        _ => None,
+    };
+    let (Some(from), Some(to)) = (*last_cursor, cursor) else {
+        *last_cursor = cursor;
+        return;
+    };
+    *last_cursor = cursor;
+    if from == to {
+        return;
+    }
+
+    // Rolling the arcball rotates the scene, so the camera needs to rotate the opposite way. The
+    // rotation is in the camera's frame of reference, so we also need to convert it to global
+    // space for each camera.
+    let view_rotation = Quat::from_rotation_arc(from, to).inverse();
+    for mut controlled_camera in q.iter_mut() {
+        if controlled_camera.mode != DebugCameraMode::Arcball
+            || controlled_camera.transition.is_some()
+        {
+            continue;
+        }
+        let camera_rotation = controlled_camera.pose().rotation();
+        let rotation = camera_rotation * view_rotation * camera_rotation.inverse();
+
+        let focus = controlled_camera.focus();
+        controlled_camera.fwd = rotation * controlled_camera.fwd;
+        controlled_camera.up = rotation * controlled_camera.up;
+        controlled_camera.position =
+            focus - controlled_camera.fwd * controlled_camera.focus_distance;
+    }
+}
+
+/// This system moves debug cameras to frame entities. It handles [`DebugCameraFocus`] events, and
+/// focuses on the entity in [`DebugCameraSelection`] (if any) when the focus binding is pressed.
+/// Cameras keep their viewing direction and back away from the entity far enough for its bounding
+/// sphere to fit in view, transitioning over their `transition_duration`. The focus distance is set
+/// to the entity's center, so orbiting afterwards will orbit around it.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_focus_system(
+    mut q: Query<(Entity, &mut DebugCamera, Option<&Projection>)>,
+    targets: Query<(&GlobalTransform, Option<&Aabb>)>,
+    mut focus_evr: EventReader<DebugCameraFocus>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    active_gamepad: Res<ActiveGamepad>,
+    selection: Res<DebugCameraSelection>,
+) {
+    let mut requests: Vec<DebugCameraFocus> = focus_evr.read().copied().collect();
+
+    if !debug_camera_active.esc_toggled {
+        let mut pressed =
+            debug_camera_active.keymouse && keys.just_pressed(keyboard_bindings.focus);
+        if debug_camera_active.gamepad {
+            if let Some(gamepad) = active_gamepad.0 {
+                pressed |=
+                    buttons.just_pressed(GamepadButton::new(gamepad, gamepad_bindings.focus));
+            }
+        }
+        if let (true, Some(target)) = (pressed, selection.0) {
+            requests.push(DebugCameraFocus {
+                target,
+                camera: None,
+            });
+        }
+    }
+
+    for request in requests {
+        let Ok((transform, aabb)) = targets.get(request.target) else {
+            continue;
+        };
+        // We frame the bounding sphere of the entity's AABB, which fits regardless of orientation
+        let (center, radius) = match aabb {
+            Some(aabb) => {
+                let (scale, _, _) = transform.to_scale_rotation_translation();
+                (
+                    transform.transform_point(aabb.center.into()),
+                    (Vec3::from(aabb.half_extents) * scale).length(),
+                )
+            }
+            None => (transform.translation(), DEFAULT_FOCUS_RADIUS),
+        };
+
+        for (entity, mut controlled_camera, projection) in q.iter_mut() {
+            if request.camera.is_some_and(|camera| camera != entity) {
+                continue;
+            }
+            let distance =
+                (FOCUS_MARGIN * framing_distance(radius, projection)).max(MIN_FOCUS_DISTANCE);
+            let mut pose = controlled_camera.pose();
+            pose.position = center - controlled_camera.fwd.normalize() * distance;
+            controlled_camera.focus_distance = distance;
+            let duration = controlled_camera.transition_duration;
+            controlled_camera.transition_to(pose, duration);
+        }
+    }
+}
+
+/// This system switches cameras between perspective and orthographic projections, and snaps them
+/// to axis-aligned views. The projection binding toggles `orthographic` on every camera, and the
+/// view bindings transition cameras to look at their focus point from the front, side or top (or
+/// the opposite sides while holding the opposite modifier), keeping their focus distance. It then
+/// keeps each camera's [`Projection`] in sync with its `orthographic` setting, sizing orthographic
+/// projections it switched to match the area a perspective projection would show at the focus
+/// distance. Cameras spawned with an orthographic projection start with `orthographic` set, and
+/// their projection is left as is.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_projection_system(
+    mut q: Query<(Entity, &mut DebugCamera, Option<&mut Projection>)>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    active_gamepad: Res<ActiveGamepad>,
+    // The perspective projections of the cameras we switched to an orthographic projection
+    mut perspectives: Local<HashMap<Entity, PerspectiveProjection>>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    let mut toggle = false;
+    // The direction to look in and the up vector of the requested view, if any
+    let mut view = None;
+    if debug_camera_active.gamepad {
+        if let Some(gamepad) = active_gamepad.0 {
+            toggle |= buttons
+                .just_pressed(GamepadButton::new(gamepad, gamepad_bindings.projection))
+                && !buttons.pressed(GamepadButton::new(gamepad, gamepad_bindings.clip_modifier));
+        }
+    }
+    if debug_camera_active.keymouse {
+        toggle |= keys.just_pressed(keyboard_bindings.projection);
+        let sign = if keys.pressed(keyboard_bindings.view_opposite) {
+            -1.
+        } else {
+            1.
+        };
+        if keys.just_pressed(keyboard_bindings.view_front) {
+            view = Some((sign * Vec3::NEG_Z, Vec3::Y));
+        } else if keys.just_pressed(keyboard_bindings.view_side) {
+            view = Some((sign * Vec3::NEG_X, Vec3::Y));
+        } else if keys.just_pressed(keyboard_bindings.view_top) {
+            view = Some((sign * Vec3::NEG_Y, sign * Vec3::NEG_Z));
+        }
+    }
+
+    for (entity, mut controlled_camera, projection) in q.iter_mut() {
+        // Otherwise, we'd replace the projection of cameras spawned as orthographic right away
+        if controlled_camera.is_added()
+            && matches!(projection.as_deref(), Some(Projection::Orthographic(_)))
+        {
+            controlled_camera.orthographic = true;
+        }
+        if toggle {
+            controlled_camera.orthographic = !controlled_camera.orthographic;
+        }
+        if let Some((fwd, up)) = view {
+            let pose = DebugCameraPose {
+                position: controlled_camera.focus() - fwd * controlled_camera.focus_distance,
+                fwd,
+                up,
+            };
+            let duration = controlled_camera.transition_duration;
+            controlled_camera.transition_to(pose, duration);
+        }
+
+        let Some(mut projection) = projection else {
+            continue;
+        };
+        match (controlled_camera.orthographic, projection.as_ref()) {
+            (true, Projection::Perspective(perspective)) => {
+                perspectives.insert(entity, perspective.clone());
+                *projection = Projection::Orthographic(OrthographicProjection {
+                    far: perspective.far,
+                    ..default()
+                });
+            }
+            // Cameras that started out orthographic have no perspective projection to go back to,
+            // so they get a default one
+            (false, Projection::Orthographic(_)) => {
+                let perspective = perspectives.remove(&entity).unwrap_or_default();
+                *projection = Projection::Perspective(perspective);
+                continue;
+            }
+            _ => {}
+        }
+
+        // A perspective projection shows an area of this height at the focus distance. We only
+        // touch the projection when it needs updating, to avoid triggering change detection.
+        let Some(perspective) = perspectives.get(&entity) else {
+            continue;
+        };
+        let height = 2. * controlled_camera.focus_distance * (perspective.fov / 2.).tan();
+        match projection.as_ref() {
+            Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical(current),
+                ..
+            }) if *current == height => {}
+            Projection::Orthographic(_) => {
+                if let Projection::Orthographic(orthographic) = projection.as_mut() {
+                    orthographic.scaling_mode = ScalingMode::FixedVertical(height);
+                }
+            }
+            Projection::Perspective(_) => {}
+        }
+    }
+}
+
+/// This system changes the field of view of cameras with a perspective [`Projection`]. Holding the
+/// field of view zoom mouse button zooms in according to each camera's
+/// [`crate::DebugCameraFovZoom`] settings, and scrolling while holding it changes the
+/// magnification. Scrolling while holding the dolly zoom binding changes the field of view while
+/// moving the camera along its view direction, so that the area around its focus point keeps the
+/// same size on screen.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_fov_system(
+    mut q: Query<(&mut DebugCamera, &mut Projection)>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut wheel_evr: EventReader<MouseWheel>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    mouse_bindings: Res<MouseBindings>,
+    navigation_scheme: Res<NavigationScheme>,
+) {
+    let wheel = wheel_evr.read().map(scroll_lines).sum::<f32>();
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    let keymouse = debug_camera_active.keymouse;
+    let zooming =
+        keymouse && fov_zoom_scrolling(&navigation_scheme, &mouse_buttons, &mouse_bindings);
+    let dolly_zooming = keymouse && keys.pressed(keyboard_bindings.dolly_zoom);
+
+    for (mut controlled_camera, mut projection) in q.iter_mut() {
+        let Projection::Perspective(perspective) = projection.as_ref() else {
+            continue;
+        };
+        let mut fov_zoom = controlled_camera.fov_zoom;
+        // The field of view the camera would have without any magnification
+        let mut base_fov = perspective.fov * fov_zoom.current;
+
+        if dolly_zooming && wheel != 0. && controlled_camera.transition.is_none() {
+            // The area at the focus point has a height of 2 * distance * tan(fov / 2), which we
+            // keep constant by moving the camera to make up for the change in field of view
+            let fov = perspective.fov;
+            let new_fov = (fov * (-ORBIT_SCROLL_STEP * wheel).exp())
+                .clamp(DOLLY_ZOOM_MIN_FOV, DOLLY_ZOOM_MAX_FOV);
+            let focus = controlled_camera.focus();
+            let distance = (controlled_camera.focus_distance * (fov / 2.).tan()
+                / (new_fov / 2.).tan())
+            .max(MIN_FOCUS_DISTANCE);
+            controlled_camera.focus_distance = distance;
+            controlled_camera.position = focus - controlled_camera.fwd.normalize() * distance;
+            base_fov *= new_fov / fov;
+        }
+
+        if zooming {
+            fov_zoom.magnification = (fov_zoom.magnification * (ORBIT_SCROLL_STEP * wheel).exp())
+                .clamp(fov_zoom.min_magnification, fov_zoom.max_magnification);
+        }
+        let target = if zooming { fov_zoom.magnification } else { 1. };
+        // Magnification changes exponentially, which keeps the smoothing independent of frame
+        // rate and the zoom speed consistent at every magnification
+        fov_zoom.current = if fov_zoom.smoothing > 0. {
+            let t = 1. - (-fov_zoom.smoothing * time.delta_seconds()).exp();
+            fov_zoom.current * (target / fov_zoom.current).powf(t)
+        } else {
+            target
+        };
+
+        let fov = base_fov / fov_zoom.current;
+        if fov_zoom != controlled_camera.fov_zoom {
+            controlled_camera.fov_zoom = fov_zoom;
+        }
+        if fov != perspective.fov {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                perspective.fov = fov;
+            }
+        }
+    }
+}
+
+/// This system applies the [`DebugCameraClipPlanes`] overrides to the [`Projection`] of every debug
+/// camera, and adjusts them while the clip plane bindings are held. The projections' original
+/// planes are remembered, and restored once an override is cleared. Planes are always kept at
+/// least `min_gap` apart, including from a projection's own plane when only the other one is
+/// overridden.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_clip_system(
+    mut q: Query<(Entity, &mut Projection), With<DebugCamera>>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    active_gamepad: Res<ActiveGamepad>,
+    mut clip_planes: ResMut<DebugCameraClipPlanes>,
+    mut originals: Local<HashMap<Entity, (f32, f32)>>,
+) {
+    if !debug_camera_active.esc_toggled {
+        // Directions in which to move the near and far planes, and whether to reset them
+        let mut near = 0.;
+        let mut far = 0.;
+        let mut reset = false;
+        if debug_camera_active.gamepad {
+            if let Some(gamepad) = active_gamepad.0 {
+                let pressed = |button| buttons.pressed(GamepadButton::new(gamepad, button));
+                if pressed(gamepad_bindings.clip_modifier) {
+                    near += buttons_to_dir(
+                        pressed(gamepad_bindings.near_plane_out),
+                        pressed(gamepad_bindings.near_plane_in),
+                    );
+                    far += buttons_to_dir(
+                        pressed(gamepad_bindings.far_plane_out),
+                        pressed(gamepad_bindings.far_plane_in),
+                    );
+                    reset |= buttons
+                        .just_pressed(GamepadButton::new(gamepad, gamepad_bindings.clip_reset));
+                }
+            }
+        }
+        if debug_camera_active.keymouse {
+            near += buttons_to_dir(
+                keys.pressed(keyboard_bindings.near_plane_out),
+                keys.pressed(keyboard_bindings.near_plane_in),
+            );
+            far += buttons_to_dir(
+                keys.pressed(keyboard_bindings.far_plane_out),
+                keys.pressed(keyboard_bindings.far_plane_in),
+            );
+            reset |= keys.just_pressed(keyboard_bindings.clip_reset);
+        }
+
+        if reset {
+            clip_planes.reset();
+        } else if near != 0. || far != 0. {
+            // Adjustments start from the first camera's current planes if there's no override yet
+            let (current_near, current_far) = q
+                .iter()
+                .next()
+                .map_or((0.1, 1000.), |(_, projection)| clip_planes_of(projection));
+            let rate = clip_planes.rate * time.delta_seconds();
+            let min_gap = clip_planes.min_gap;
+            // Each plane is limited by the other one, whether it's overridden or not
+            if near != 0. {
+                let value = clip_planes
+                    .near
+                    .unwrap_or(current_near)
+                    .max(clip_planes.near_min);
+                let max = clip_planes.far.unwrap_or(current_far) - min_gap;
+                clip_planes.set_near((value * (rate * near).exp()).min(max));
+            }
+            if far != 0. {
+                let value = clip_planes.far.unwrap_or(current_far);
+                let min = clip_planes.near.unwrap_or(current_near) + min_gap;
+                clip_planes.set_far((value * (rate * far).exp()).max(min));
+            }
+        }
+    }
+
+    for (entity, mut projection) in q.iter_mut() {
+        let (near, far) = clip_planes_of(&projection);
+        let original = *originals.entry(entity).or_insert((near, far));
+        let target = match (clip_planes.near, clip_planes.far) {
+            // Cameras can have different original planes than the one adjustments started from,
+            // so we make sure overriding one plane doesn't push it past the other one here too
+            (Some(near), None) => (near.min(original.1 - clip_planes.min_gap), original.1),
+            (None, Some(far)) => (original.0, far.max(original.0 + clip_planes.min_gap)),
+            (near, far) => (near.unwrap_or(original.0), far.unwrap_or(original.1)),
+        };
+        if clip_planes.near.is_none() && clip_planes.far.is_none() {
+            originals.remove(&entity);
+        }
+        // Avoid triggering change detection when nothing changed
+        if target == (near, far) {
+            continue;
+        }
+        match projection.as_mut() {
+            Projection::Perspective(perspective) => {
+                (perspective.near, perspective.far) = target;
+            }
+            Projection::Orthographic(orthographic) => {
+                (orthographic.near, orthographic.far) = target;
+            }
+        }
+    }
+}
+
+/// This system saves and recalls [`DebugCameraBookmarks`] with the bookmark bindings. Saving logs
+/// the saved pose through a tracing event, and recalling transitions every camera to the saved pose
+/// over its `transition_duration`. Only keyboard input is supported.
+pub fn camera_bookmark_system(
+    mut q: Query<&mut DebugCamera>,
+    keys: Res<Input<KeyCode>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    mut bookmarks: ResMut<DebugCameraBookmarks>,
+) {
+    if !debug_camera_active.keymouse || debug_camera_active.esc_toggled {
+        return;
+    }
+    let Some(slot) = keyboard_bindings
+        .bookmarks
+        .iter()
+        .position(|key| keys.just_pressed(*key))
+    else {
+        return;
+    };
+
+    if keys.pressed(keyboard_bindings.bookmark_save) {
+        let Some(pose) = q.iter().next().map(|camera| camera.pose()) else {
+            return;
+        };
+        event!(
+            Level::INFO,
+            event = "debug_camera_bookmark_saved",
+            slot,
+            position = ?pose.position,
+            fwd = ?pose.fwd,
+            up = ?pose.up,
+        );
+        bookmarks.set(slot, pose);
+    } else if let Some(pose) = bookmarks.get(slot) {
+        for mut controlled_camera in q.iter_mut() {
+            let duration = controlled_camera.transition_duration;
+            controlled_camera.transition_to(pose, duration);
+        }
+    }
+}
+
+/// What [`camera_history_system`] remembers about the first camera between frames.
+#[derive(Default)]
+pub struct HistoryTracker {
+    /// The camera's pose last frame.
+    pose: Option<DebugCameraPose>,
+    /// How long (in seconds) the camera has been still for.
+    still_for: f32,
+    /// Whether the pose the camera is resting at has been recorded.
+    settled: bool,
+    /// Whether the camera was in a transition last frame.
+    in_transition: bool,
+}
+
+/// This system records poses into the [`DebugCameraHistory`] and navigates it with the history
+/// bindings. It should run before [`camera_transition_system`], so that it sees where cameras were
+/// before a transition moves them. Instant jumps are recorded from each camera's `jumped_from`,
+/// which is cleared on every camera. Only keyboard input is supported for navigation.
+pub fn camera_history_system(
+    mut q: Query<&mut DebugCamera>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    mut history: ResMut<DebugCameraHistory>,
+    mut tracker: Local<HistoryTracker>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+    // Only the first camera's jumps are recorded, but we clear them on every camera so that they
+    // don't go stale
+    let mut jumped_from = None;
+    for (i, mut controlled_camera) in q.iter_mut().enumerate() {
+        if controlled_camera.jumped_from.is_some() {
+            let from = controlled_camera.jumped_from.take();
+            if i == 0 {
+                jumped_from = from;
+            }
+        }
+    }
+    let Some(camera) = q.iter().next() else {
+        return;
+    };
+    let pose = camera.pose();
+    let in_transition = camera.transition.is_some();
+
+    let navigate_to = if !debug_camera_active.keymouse {
+        None
+    } else if keys.just_pressed(keyboard_bindings.history_back) {
+        history.back(pose)
+    } else if keys.just_pressed(keyboard_bindings.history_forward) {
+        history.forward()
+    } else {
+        None
+    };
+    if let Some(target) = navigate_to {
+        for mut controlled_camera in q.iter_mut() {
+            let duration = controlled_camera.transition_duration;
+            controlled_camera.transition_to(target, duration);
+            controlled_camera.jumped_from = None;
+        }
+        // We're moving to a pose that's already recorded, so there's nothing to record on the way
+        *tracker = HistoryTracker {
+            pose: Some(pose),
+            in_transition: true,
+            ..default()
+        };
+        return;
+    }
+
+    if tracker.pose.is_some_and(|last| same_pose(&last, &pose)) {
+        tracker.still_for += time.delta_seconds();
+    } else {
+        tracker.still_for = 0.;
+        tracker.settled = false;
+    }
+    // Instant jumps (such as teleports, or transitions with no duration) have already moved the
+    // camera by the time we see them, so we record where it jumped from
+    if let Some(from) = jumped_from {
+        history.record(from);
+    }
+    // Record where we were before transitioning somewhere else, and wherever we come to rest. The
+    // history ignores poses that are already recorded.
+    let jumping = in_transition && !tracker.in_transition;
+    let settling = !tracker.settled && tracker.still_for >= history.settle_time;
+    if jumping || settling {
+        history.record(pose);
+    }
+    tracker.settled |= settling;
+    tracker.pose = Some(pose);
+    tracker.in_transition = in_transition;
+}
+
+/// This system logs the pose of every camera as a string when the copy pose binding is pressed, so
+/// it can be copied from the logs and shared. See [`DebugCameraPose`]'s `Display` implementation
+/// for the format. Only keyboard input is supported.
+pub fn camera_copy_pose_system(
+    q: Query<&DebugCamera>,
+    keys: Res<Input<KeyCode>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+) {
+    if !debug_camera_active.keymouse
+        || debug_camera_active.esc_toggled
+        || !keys.just_pressed(keyboard_bindings.copy_pose)
+    {
+        return;
+    }
+    for controlled_camera in q.iter() {
+        event!(
+            Level::INFO,
+            event = "debug_camera_pose",
+            pose = %controlled_camera.pose(),
+        );
+    }
+}
+
+/// This system advances any ongoing [`crate::DebugCameraTransition`]s, moving cameras along them.
+/// It should run after [`camera_movement_system`], as transitions override any input.
+pub fn camera_transition_system(
+    mut q: Query<&mut DebugCamera>,
+    time: Res<Time>,
+    debug_camera_active: Res<DebugCameraActive>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    for mut controlled_camera in q.iter_mut() {
+        let Some(mut transition) = controlled_camera.transition else {
+            continue;
+        };
+        let (pose, finished) = transition.advance(time.delta_seconds());
+        controlled_camera.position = pose.position;
+        controlled_camera.fwd = pose.fwd;
+        controlled_camera.up = pose.up;
+        controlled_camera.velocity = Vec3::ZERO;
+        controlled_camera.angular_velocity = Vec3::ZERO;
+        controlled_camera.transition = if finished { None } else { Some(transition) };
+    }
+}
+
+/// This system moves cameras in [`DebugCameraMode::Follow`] along with their target entity, based
+/// on each camera's [`crate::DebugCameraFollow`] settings. Cameras whose target no longer exists
+/// are switched back to [`DebugCameraMode::Fly`]. It also toggles following the entity in
+/// [`DebugCameraSelection`] when the follow binding is pressed.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_follow_system(
+    mut q: Query<&mut DebugCamera>,
+    targets: Query<&GlobalTransform>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    active_gamepad: Res<ActiveGamepad>,
+    selection: Res<DebugCameraSelection>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    let mut toggle = debug_camera_active.keymouse && keys.just_pressed(keyboard_bindings.follow);
+    if debug_camera_active.gamepad {
+        if let Some(gamepad) = active_gamepad.0 {
+            toggle |= buttons.just_pressed(GamepadButton::new(gamepad, gamepad_bindings.follow));
+        }
+    }
+
+    for mut controlled_camera in q.iter_mut() {
+        if toggle {
+            controlled_camera.mode = match (controlled_camera.mode, selection.0) {
+                (DebugCameraMode::Follow(_), _) => DebugCameraMode::Fly,
+                (_, Some(target)) => DebugCameraMode::Follow(target),
+                (mode, None) => mode,
+            };
+        }
+
+        let DebugCameraMode::Follow(target) = controlled_camera.mode else {
+            continue;
+        };
+        if controlled_camera.transition.is_some() {
+            continue;
+        }
+        let Ok(transform) = targets.get(target) else {
+            controlled_camera.mode = DebugCameraMode::Fly;
+            continue;
+        };
+
+        let follow = controlled_camera.follow;
+        let (_, target_rotation, target_position) = transform.to_scale_rotation_translation();
+        let desired_position = target_position + target_rotation * follow.offset;
+
+        // The camera catches up with the target exponentially, which keeps the lag independent
+        // of frame rate
+        let catch_up = if follow.lag > 0. {
+            1. - (-time.delta_seconds() / follow.lag).exp()
+        } else {
+            1.
+        };
+        let current = controlled_camera.pose();
+        let position = current.position.lerp(desired_position, catch_up);
+        let rotation = if follow.look_at {
+            Transform::from_translation(position)
+                .looking_at(target_position, Vec3::Y)
+                .rotation
+        } else {
+            current.rotation().slerp(target_rotation, catch_up)
+        };
+        let pose = DebugCameraPose::from_rotation(position, rotation);
+
+        controlled_camera.position = pose.position;
+        controlled_camera.fwd = pose.fwd;
+        controlled_camera.up = pose.up;
+        if follow.look_at {
+            // This way, switching to orbit afterwards orbits around the target
+            controlled_camera.focus_distance =
+                position.distance(target_position).max(MIN_FOCUS_DISTANCE);
+        }
+    }
+}
+
+/// This system keeps cameras in [`DebugCameraMode::Walk`] on the ground. It applies gravity,
+/// jumping and crouching to the camera's vertical velocity and position, using the
+/// [`DebugCameraGround`] resource to find the ground's height (or a flat plane at y = 0 if the
+/// resource doesn't exist). Ground rising by more than a step blocks horizontal movement. It
+/// should run after [`camera_movement_system`].
+#[allow(clippy::too_many_arguments)]
+pub fn camera_walk_system(
+    mut q: Query<(Entity, &mut DebugCamera)>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    buttons: Res<Input<GamepadButton>>,
+    active_gamepad: Res<ActiveGamepad>,
+    ground: Option<Res<DebugCameraGround>>,
+    // Each camera's position and the height of its feet at the end of last frame
+    mut previous_positions: Local<HashMap<Entity, (Vec3, f32)>>,
+) {
+    if !(debug_camera_active.gamepad || debug_camera_active.keymouse)
+        || debug_camera_active.esc_toggled
+    {
+        previous_positions.clear();
+        return;
+    }
+
+    let mut jump = false;
+    let mut crouch = false;
+    if debug_camera_active.gamepad {
+        if let Some(gamepad) = active_gamepad.0 {
+            jump |= buttons.just_pressed(GamepadButton::new(gamepad, gamepad_bindings.jump));
+            crouch |= buttons.pressed(GamepadButton::new(gamepad, gamepad_bindings.crouch));
+        }
+    }
+    if debug_camera_active.keymouse {
+        jump |= keys.just_pressed(keyboard_bindings.jump);
+        crouch |= keys.pressed(keyboard_bindings.crouch);
+    }
+
+    let delta_seconds = time.delta_seconds();
+    for (entity, mut controlled_camera) in q.iter_mut() {
+        // Transitions take precedence over walking, so we don't fight over the camera's height
+        if controlled_camera.mode != DebugCameraMode::Walk || controlled_camera.transition.is_some()
+        {
+            previous_positions.remove(&entity);
+            continue;
+        }
+
+        let walk = controlled_camera.walk;
+        let eye_height = if crouch {
+            walk.crouch_eye_height
+        } else {
+            walk.eye_height
+        };
+        let ground_height_at = |position: Vec3| match &ground {
+            Some(ground) => ground.0.ground_height(position),
+            None => Some(0.),
+        };
+
+        // Ground that rises by more than a step above our feet acts as a wall. Rather than
+        // climbing on top of it, we keep whichever part of this frame's horizontal motion doesn't
+        // run into it, so the camera slides along walls. Our feet are where they were last frame,
+        // as changing the eye height (such as when standing up) doesn't move them.
+        if let Some(&(previous, feet)) = previous_positions.get(&entity) {
+            let position = controlled_camera.position;
+            let max_ground = feet + walk.step_height;
+            let walkable = |position: Vec3| match ground_height_at(position) {
+                Some(height) => height <= max_ground,
+                None => true,
+            };
+            if !walkable(position) {
+                let along_x = Vec3::new(position.x, position.y, previous.z);
+                let along_z = Vec3::new(previous.x, position.y, position.z);
+                let (position, blocked) = if walkable(along_x) {
+                    (along_x, Vec3::Z)
+                } else if walkable(along_z) {
+                    (along_z, Vec3::X)
+                } else {
+                    (
+                        Vec3::new(previous.x, position.y, previous.z),
+                        Vec3::X + Vec3::Z,
+                    )
+                };
+                controlled_camera.position = position;
+                controlled_camera.velocity *= Vec3::ONE - blocked;
+            }
+        }
+
+        let position = controlled_camera.position;
+        let ground_height = ground_height_at(position);
+
+        // Cameras standing on the ground have no vertical velocity. Gravity always applies, and we
+        // then correct for the ground below.
+        let was_grounded = controlled_camera.velocity.y == 0.;
+        controlled_camera.velocity.y -= walk.gravity * delta_seconds;
+        if let Some(ground_height) = ground_height {
+            let eye = ground_height + eye_height;
+
+            // We stick to the ground if we're below eye height, or if we were standing on it last
+            // frame and it drops by less than a step (e.g. when walking down a slope or stairs).
+            // Cameras that are jumping or falling are otherwise left alone.
+            if position.y <= eye || (was_grounded && position.y - eye <= walk.step_height) {
+                controlled_camera.position.y = eye;
+                controlled_camera.velocity.y = if jump { walk.jump_speed } else { 0. };
+            }
+        }
+
+        let position = controlled_camera.position;
+        previous_positions.insert(entity, (position, position.y - eye_height));
+    }
+}
+
+/// This system moves cameras in [`DebugCameraMode::TopDown`]. Movement input and the cursor being
+/// near the edges of the window pan the camera's focus point along the ground, the roll bindings
+/// rotate around it, and moving up and down or scrolling zooms. The camera is then placed above
+/// the focus point according to its [`crate::DebugCameraTopDown`] settings. The ground's height
+/// comes from the [`DebugCameraGround`] resource, or a flat plane at y = 0 if it doesn't exist. It
+/// should run after [`camera_movement_system`].
 #[allow(clippy::too_many_arguments)]
-pub fn camera_movement_system(
+pub fn camera_top_down_system(
     mut q: Query<&mut DebugCamera>,
+    windows: Query<&Window, With<PrimaryWindow>>,
     time: Res<Time>,
     keys: Res<Input<KeyCode>>,
-    mut debug_camera_active: ResMut<DebugCameraActive>,
+    mut wheel_evr: EventReader<MouseWheel>,
+    debug_camera_active: Res<DebugCameraActive>,
     keyboard_bindings: Res<KeyboardBindings>,
     gamepad_bindings: Res<GamepadBindings>,
-    mut motion_evr: EventReader<MouseMotion>,
     axes: Res<Axis<GamepadAxis>>,
     buttons: Res<Input<GamepadButton>>,
     button_axes: Res<Axis<GamepadButton>>,
-    active_gamepad: ResMut<ActiveGamepad>,
+    active_gamepad: Res<ActiveGamepad>,
+    ground: Option<Res<DebugCameraGround>>,
 ) {
-    // Shortcut if neither control scheme is active. This is not strictly needed, but it avoids
-    // some computation if controls are inactive.
-    if !(debug_camera_active.gamepad || debug_camera_active.keymouse) {
+    if debug_camera_active.esc_toggled {
         return;
     }
 
+    // Zoom is in zoom levels, with positive values zooming out
+    let mut input = MovementInput::default();
+    let mut zoom = 0.;
+    // The cursor's position and the window's size, used for edge scrolling with each camera's own
+    // margin
+    let mut cursor = None;
+
     if debug_camera_active.gamepad {
         if let Some(gamepad) = active_gamepad.0 {
-            if button_axes.get(GamepadButton::new(gamepad, gamepad_bindings.esc)).is_some() {
-                debug_camera_active.esc_toggled = !debug_camera_active.esc_toggled;
-                return;
-            }
+            input += gamepad_movement_input(
+                gamepad,
+                time.delta_seconds(),
+                &gamepad_bindings,
+                &axes,
+                &buttons,
+                &button_axes,
+            );
         }
     }
 
     if debug_camera_active.keymouse {
-        if keys.just_released(keyboard_bindings.esc) {
-            debug_camera_active.esc_toggled = !debug_camera_active.esc_toggled;
-            return;
+        input += keyboard_movement_input(time.delta_seconds(), &keyboard_bindings, &keys);
+        // Scrolling up zooms in, like it does when orbiting
+        zoom -= TOP_DOWN_SCROLL_STEP * wheel_evr.read().map(scroll_lines).sum::<f32>();
+        cursor = windows.get_single().ok().and_then(|window| {
+            let size = Vec2::new(window.width(), window.height());
+            window.cursor_position().map(|position| (position, size))
+        });
+    }
+
+    // Panning is on the ground plane (x = fwd, y = right), and rotation is a yaw around the
+    // world's up axis, controlled by both the yaw and roll bindings. Moving up and down zooms.
+    let pan = Vec2::new(input.translate.x, input.translate.z);
+    let yaw = input.rotate.x - input.rotate.z;
+    zoom += TOP_DOWN_ZOOM_RATE * input.translate.y;
+
+    for mut controlled_camera in q.iter_mut() {
+        if controlled_camera.mode != DebugCameraMode::TopDown
+            || controlled_camera.transition.is_some()
+        {
+            continue;
+        }
+
+        let modifier = speed_modifier(&controlled_camera, input.boost, input.precision);
+
+        let mut pan = pan;
+        let margin = controlled_camera.top_down.edge_scroll_margin;
+        if let (Some((position, size)), true) = (cursor, margin > 0.) {
+            // Window coordinates have y pointing down, so the top edge pans forward
+            pan += time.delta_seconds()
+                * Vec2::new(
+                    buttons_to_dir(position.y < margin, position.y > size.y - margin),
+                    buttons_to_dir(position.x > size.x - margin, position.x < margin),
+                );
         }
+
+        let top_down = &mut controlled_camera.top_down;
+        top_down.zoom = (top_down.zoom + zoom).clamp(0., 1.);
+        let top_down = *top_down;
+        // Heights are interpolated exponentially, so they need to be positive
+        let min_height = top_down.min_height.max(MIN_FOCUS_DISTANCE);
+        let max_height = top_down.max_height.max(min_height);
+        let height = min_height * (max_height / min_height).powf(top_down.zoom);
+        let pitch = top_down.min_pitch + (top_down.max_pitch - top_down.min_pitch) * top_down.zoom;
+
+        // We rotate the heading around the focus point, and then pan along the new heading. Panning
+        // speeds up as the camera gets higher, so the ground scrolls across the screen at the same
+        // rate regardless of zoom.
+        let heading = Vec3::new(controlled_camera.fwd.x, 0., controlled_camera.fwd.z)
+            .try_normalize()
+            .unwrap_or(Vec3::X);
+        let heading =
+            Quat::from_rotation_y(modifier * controlled_camera.speed_rotate * yaw) * heading;
+        let right = heading.cross(Vec3::Y);
+        let pan_speed = modifier * controlled_camera.speed_translate * height / min_height;
+        let mut focus = controlled_camera.focus() + pan_speed * (heading * pan.x + right * pan.y);
+        focus.y = match &ground {
+            Some(ground) => ground.0.ground_height(focus).unwrap_or(focus.y),
+            None => 0.,
+        };
+
+        let fwd = heading * pitch.cos() - Vec3::Y * pitch.sin();
+        let distance = height / pitch.sin().max(MIN_FOCUS_DISTANCE);
+        controlled_camera.fwd = fwd;
+        controlled_camera.up = right.cross(fwd);
+        controlled_camera.focus_distance = distance;
+        controlled_camera.position = focus - fwd * distance;
     }
+}
 
+/// This system moves cameras in [`DebugCameraMode::Ortho2d`] across the xy plane, based on each
+/// camera's [`crate::DebugCameraOrtho2d`] settings. Movement input and dragging with the pan mouse
+/// button pan the camera, the roll bindings rotate it (if enabled), and moving up and down or
+/// scrolling zooms by changing the scale of its [`OrthographicProjection`]. Scrolling keeps the
+/// point under the cursor in place. It should run after [`camera_movement_system`].
+#[allow(clippy::too_many_arguments)]
+pub fn camera_ortho_2d_system(
+    mut q: Query<(&mut DebugCamera, Option<&mut OrthographicProjection>)>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    mouse_buttons: Res<Input<MouseButton>>,
+    mut motion_evr: EventReader<MouseMotion>,
+    mut wheel_evr: EventReader<MouseWheel>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    mouse_bindings: Res<MouseBindings>,
+    axes: Res<Axis<GamepadAxis>>,
+    buttons: Res<Input<GamepadButton>>,
+    button_axes: Res<Axis<GamepadButton>>,
+    active_gamepad: Res<ActiveGamepad>,
+) {
     if debug_camera_active.esc_toggled {
         return;
     }
 
-    // All calculations before going into each camera are done from the camera's frame
-    // of reference. We assume x = fwd, y = right, z = up
-    let mut rotate_vec = Vec3::default();
-    let mut local_translate_vec = Vec3::default();
+    let mut input = MovementInput::default();
+    // Mouse input, in pixels and lines respectively. These don't depend on the camera's speed.
+    let mut drag = Vec2::default();
+    let mut scroll = 0.;
 
-    // First, apply controller if present and active
     if debug_camera_active.gamepad {
         if let Some(gamepad) = active_gamepad.0 {
-            // Apply translation
-            if let (Some(x), Some(y), Some(down), Some(up)) = (
-                axes.get(GamepadAxis::new(gamepad, gamepad_bindings.left_right)),
-                axes.get(GamepadAxis::new(gamepad, gamepad_bindings.fwd_bwd)),
-                button_axes.get(GamepadButton::new(gamepad, gamepad_bindings.down)),
-                button_axes.get(GamepadButton::new(gamepad, gamepad_bindings.up)),
-            ) {
-                let up_down = up - down;
-                local_translate_vec += time.delta_seconds() * Vec3::new(y, up_down, x);
+            input += gamepad_movement_input(
+                gamepad,
+                time.delta_seconds(),
+                &gamepad_bindings,
+                &axes,
+                &buttons,
+                &button_axes,
+            );
+        }
+    }
+
+    if debug_camera_active.keymouse {
+        input += keyboard_movement_input(time.delta_seconds(), &keyboard_bindings, &keys);
+        let motion = motion_evr.read().map(|ev| ev.delta).sum::<Vec2>();
+        if mouse_buttons.pressed(mouse_bindings.pan) {
+            drag += motion;
+        }
+        scroll += wheel_evr.read().map(scroll_lines).sum::<f32>();
+    }
+
+    // Panning is in the camera's frame of reference (x = right, y = up), scaled by time but not by
+    // speed. The forward and backward bindings pan up and down so that WASD works as expected, and
+    // the up and down bindings zoom. Zoom is in the same units as the orbit radius: the scale is
+    // multiplied by `e^-zoom`.
+    let pan = Vec2::new(input.translate.z, input.translate.x);
+    let roll = input.rotate.z;
+    let zoom = ORBIT_ZOOM_RATE * input.translate.y;
+
+    // Everything is measured against the primary window, which we assume the camera fills
+    let window = windows.get_single().ok();
+    for (mut controlled_camera, mut projection) in q.iter_mut() {
+        if controlled_camera.mode != DebugCameraMode::Ortho2d
+            || controlled_camera.transition.is_some()
+        {
+            continue;
+        }
+
+        let modifier = speed_modifier(&controlled_camera, input.boost, input.precision);
+
+        // Keep the camera looking down -z, with its up vector on the xy plane
+        let settings = controlled_camera.ortho_2d;
+        let up = Vec3::new(controlled_camera.up.x, controlled_camera.up.y, 0.)
+            .try_normalize()
+            .unwrap_or(Vec3::Y);
+        let up = if settings.rotate {
+            let angle = modifier * controlled_camera.speed_rotate * roll;
+            Quat::from_rotation_z(-angle) * up
+        } else {
+            up
+        };
+        let right = up.cross(Vec3::Z);
+        controlled_camera.fwd = Vec3::NEG_Z;
+        controlled_camera.up = up;
+
+        // The size of the view in world units, which lets us convert pixels into world units
+        // regardless of the projection's scaling mode
+        let scale = projection
+            .as_ref()
+            .map_or(1., |projection| projection.scale);
+        let view_size = projection
+            .as_ref()
+            .map_or(Vec2::ONE, |projection| projection.area.size());
+        let window_size = window.map_or(Vec2::ONE, |window| {
+            Vec2::new(window.width(), window.height())
+        });
+        let world_per_pixel = view_size / window_size;
+
+        // Dragging moves the camera against the cursor so the scene follows it. Window
+        // coordinates have y pointing down.
+        let pan = modifier * controlled_camera.speed_translate * scale * pan
+            - drag * world_per_pixel * Vec2::new(1., -1.);
+        controlled_camera.position += right * pan.x + up * pan.y;
+
+        let Some(projection) = projection.as_mut() else {
+            continue;
+        };
+        let new_scale = (scale * (-(zoom + ORBIT_SCROLL_STEP * scroll)).exp())
+            .clamp(settings.min_scale, settings.max_scale);
+        if new_scale == scale {
+            continue;
+        }
+        projection.scale = new_scale;
+
+        // Scrolling zooms towards the cursor, so we move the camera to keep the point under it
+        // fixed. Its offset from the center of the view scales along with the projection.
+        let cursor = window.and_then(|window| window.cursor_position());
+        if let (Some(cursor), true) = (cursor, scroll != 0.) {
+            let offset = (cursor - window_size / 2.) * world_per_pixel * Vec2::new(1., -1.);
+            let shift = offset * (1. - new_scale / scale);
+            controlled_camera.position += right * shift.x + up * shift.y;
+        }
+    }
+}
+
+/// This system enforces [`DebugCameraConstraints`] on any cameras that have them, pushing cameras
+/// out of [`DebugCameraCollider`]s and then clamping them to their bounds. It should run after
+/// [`camera_movement_system`] and [`camera_walk_system`].
+pub fn camera_constraint_system(
+    mut q: Query<(&mut DebugCamera, &DebugCameraConstraints)>,
+    colliders: Query<(&DebugCameraCollider, &GlobalTransform)>,
+) {
+    for (mut controlled_camera, constraints) in q.iter_mut() {
+        let mut position = controlled_camera.position;
+
+        if constraints.collide {
+            for (collider, transform) in colliders.iter() {
+                position = push_out_of_collider(position, constraints.radius, collider, transform);
             }
+        }
 
-            // Apply rotation
-            if let (Some(x), Some(y), roll_left, roll_right) = (
-                axes.get(GamepadAxis::new(gamepad, gamepad_bindings.yaw)),
-                axes.get(GamepadAxis::new(gamepad, gamepad_bindings.pitch)),
-                buttons.pressed(GamepadButton::new(gamepad, gamepad_bindings.roll_left)),
-                buttons.pressed(GamepadButton::new(gamepad, gamepad_bindings.roll_right)),
-            ) {
-                let roll = buttons_to_dir(roll_right, roll_left);
-                rotate_vec += time.delta_seconds() * Vec3::new(-x, y, roll);
+        // Bounds are applied last, as they must always hold
+        match constraints.bounds {
+            Some(DebugCameraBounds::Aabb { min, max }) => {
+                position = position.clamp(min, max);
+            }
+            Some(DebugCameraBounds::Sphere { center, radius }) => {
+                let offset = position - center;
+                if offset.length() > radius {
+                    position = center + offset.normalize() * radius;
+                }
             }
+            None => {}
+        }
+
+        // Avoid triggering change detection when nothing moved
+        if position != controlled_camera.position {
+            controlled_camera.position = position;
         }
     }
+}
 
-    // Next, apply keyboard and mouse controls
-    if debug_camera_active.keymouse {
-        let key_fwd = keys.pressed(keyboard_bindings.fwd);
-        let key_bwd = keys.pressed(keyboard_bindings.bwd);
-        let key_up = keys.pressed(keyboard_bindings.up);
-        let key_down = keys.pressed(keyboard_bindings.down);
-        let key_left = keys.pressed(keyboard_bindings.left);
-        let key_right = keys.pressed(keyboard_bindings.right);
-        let key_roll_left = keys.pressed(keyboard_bindings.roll_left);
-        let key_roll_right = keys.pressed(keyboard_bindings.roll_right);
-        let mouse_delta = {
-            let mut d = Vec2::default();
-            for ev in motion_evr.read() {
-                d -= ev.delta;
-            }
-            d
-        };
+/// This system keeps cameras with a `look_at` target pointed at it, so that translating around
+/// circles the target. Roll is preserved. Targets that no longer exist are cleared. It also toggles
+/// locking on to the entity in [`DebugCameraSelection`] when the look at binding is pressed. This
+/// should run after any system that moves cameras.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_look_at_system(
+    mut q: Query<&mut DebugCamera>,
+    targets: Query<&GlobalTransform>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    gamepad_bindings: Res<GamepadBindings>,
+    active_gamepad: Res<ActiveGamepad>,
+    selection: Res<DebugCameraSelection>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
 
-        // All keyboard and mouse input is multiplied by 0.5, as otherwise it will go too fast
-        // compared with controller
-        local_translate_vec += time.delta_seconds()
-            * 0.5
-            * Vec3::new(
-                buttons_to_dir(key_fwd, key_bwd),
-                buttons_to_dir(key_up, key_down),
-                buttons_to_dir(key_right, key_left),
-            );
-        rotate_vec += time.delta_seconds()
-            * 0.5
-            * Vec3::new(
-                mouse_delta.x,
-                mouse_delta.y,
-                buttons_to_dir(key_roll_right, key_roll_left),
-            );
+    let mut toggle = debug_camera_active.keymouse && keys.just_pressed(keyboard_bindings.look_at);
+    if debug_camera_active.gamepad {
+        if let Some(gamepad) = active_gamepad.0 {
+            toggle |= buttons.just_pressed(GamepadButton::new(gamepad, gamepad_bindings.look_at))
+                && !buttons.pressed(GamepadButton::new(gamepad, gamepad_bindings.clip_modifier));
+        }
     }
 
     for mut controlled_camera in q.iter_mut() {
-        // We start by computing and correcting all our basis vectors to be unit vectors that are
-        // perpendicular to each other. This fixes any
-        let mut right = controlled_camera.fwd.cross(controlled_camera.up);
-        controlled_camera.up = right.cross(controlled_camera.fwd);
-        controlled_camera.fwd = controlled_camera.up.cross(right);
-        controlled_camera.up = controlled_camera.up.normalize();
-        controlled_camera.fwd = controlled_camera.fwd.normalize();
-        right = controlled_camera.fwd.cross(controlled_camera.up);
+        if toggle {
+            controlled_camera.look_at = match (controlled_camera.look_at, selection.0) {
+                (Some(_), _) => None,
+                (None, target) => target.map(DebugCameraLookAt::Entity),
+            };
+        }
 
-        // This matrix converts the local coordinate frame to world coordinates. General assumption
-        // is that the up and fwd vectors are unit vectors and perpendicular to each other at this
-        // point.
-        let basis_matrix = Mat3::from_cols(controlled_camera.fwd, controlled_camera.up, right);
-        let speed_translate = controlled_camera.speed_translate;
+        let target = match controlled_camera.look_at {
+            Some(DebugCameraLookAt::Point(point)) => point,
+            Some(DebugCameraLookAt::Entity(entity)) => match targets.get(entity) {
+                Ok(transform) => transform.translation(),
+                Err(_) => {
+                    controlled_camera.look_at = None;
+                    continue;
+                }
+            },
+            None => continue,
+        };
+        if controlled_camera.transition.is_some() {
+            continue;
+        }
 
-        // Translation first. It's just a simple basis matrix multiplication
-        controlled_camera.position += speed_translate * (basis_matrix * local_translate_vec);
+        // Keep the current focus distance when we're on top of the target, as there's no way to
+        // tell which way we should be facing
+        let position = controlled_camera.position;
+        if position.distance(target) > MIN_FOCUS_DISTANCE {
+            controlled_camera.set_focus(target);
+            orthonormalize(&mut controlled_camera);
+        }
+    }
+}
 
-        // Rotation last. Rotation is applied on each direction individually for simplicity
-        // x rotation is relative to the up vector. Should keep both vectors perpendicular
-        let x_rot_quat = Quat::from_axis_angle(
-            controlled_camera.up,
-            rotate_vec.x * controlled_camera.speed_rotate,
-        );
-        controlled_camera.fwd = x_rot_quat * controlled_camera.fwd;
-        right = x_rot_quat * right;
-        // y rotation is done by the right axis, which we just updated to rotate both fwd and up.
-        // Both are still perpendicular and unit vectors, so we don't need to normalise the result.
-        let y_rot_quat =
-            Quat::from_axis_angle(right, rotate_vec.y * controlled_camera.speed_rotate);
-        controlled_camera.fwd = y_rot_quat * controlled_camera.fwd;
-        controlled_camera.up = y_rot_quat * controlled_camera.up;
-        // lastly, z rotation is done relative to the fwd vector.
-        let z_rot_quat = Quat::from_axis_angle(
-            controlled_camera.fwd,
-            rotate_vec.z * controlled_camera.speed_rotate,
-        );
-        controlled_camera.up = z_rot_quat * controlled_camera.up;
+/// This system records and plays back the [`DebugCameraPathRecorder`]'s path, and toggles recording
+/// and playback with the path bindings. It should run after any other system that moves cameras,
+/// so that recordings capture their final pose and playback overrides everything else.
+pub fn camera_path_system(
+    mut q: Query<&mut DebugCamera>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    mut recorder: ResMut<DebugCameraPathRecorder>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    if debug_camera_active.keymouse {
+        if keys.just_pressed(keyboard_bindings.record_path) {
+            match recorder.state {
+                DebugCameraPathState::Recording { .. } => recorder.stop(),
+                _ => recorder.record(),
+            }
+        } else if keys.just_pressed(keyboard_bindings.play_path) {
+            match recorder.state {
+                DebugCameraPathState::Playing { .. } => recorder.stop(),
+                _ => recorder.play(),
+            }
+        }
+    }
+
+    match recorder.state {
+        DebugCameraPathState::Idle => {}
+        DebugCameraPathState::Recording { time: elapsed } => {
+            let Some(camera) = q.iter().next() else {
+                return;
+            };
+            let due = match recorder.path.samples.last() {
+                Some(last) => elapsed - last.time >= recorder.interval,
+                None => true,
+            };
+            if due {
+                let sample = DebugCameraPathSample {
+                    time: elapsed,
+                    pose: camera.pose(),
+                };
+                recorder.path.samples.push(sample);
+            }
+            recorder.state = DebugCameraPathState::Recording {
+                time: elapsed + time.delta_seconds(),
+            };
+        }
+        DebugCameraPathState::Playing { time: elapsed } => {
+            let duration = recorder.path.duration();
+            let elapsed = if elapsed <= duration {
+                elapsed
+            } else if recorder.looping && duration > 0. {
+                elapsed % duration
+            } else {
+                // Finish on the last pose, so it's never skipped over
+                recorder.stop();
+                duration
+            };
+            let Some(pose) = recorder.path.sample(elapsed) else {
+                recorder.stop();
+                return;
+            };
+            for mut controlled_camera in q.iter_mut() {
+                controlled_camera.place(pose);
+            }
+            if let DebugCameraPathState::Playing { .. } = recorder.state {
+                recorder.state = DebugCameraPathState::Playing {
+                    time: elapsed + recorder.speed * time.delta_seconds(),
+                };
+            }
+        }
+    }
+}
+
+/// This system adds and removes [`DebugCameraFlythrough`] keyframes with the keyframe bindings,
+/// and plays the flythrough. Like [`camera_path_system`], it should run after any other system that
+/// moves cameras.
+pub fn camera_flythrough_system(
+    mut q: Query<&mut DebugCamera>,
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    debug_camera_active: Res<DebugCameraActive>,
+    keyboard_bindings: Res<KeyboardBindings>,
+    mut flythrough: ResMut<DebugCameraFlythrough>,
+) {
+    if debug_camera_active.esc_toggled {
+        return;
+    }
+
+    if debug_camera_active.keymouse {
+        if keys.just_pressed(keyboard_bindings.keyframe_add) {
+            if let Some(camera) = q.iter().next() {
+                flythrough.add_keyframe(camera.pose());
+                event!(
+                    Level::INFO,
+                    event = "debug_camera_keyframe_added",
+                    keyframes = flythrough.spline.keyframes.len(),
+                );
+            }
+        } else if keys.just_pressed(keyboard_bindings.keyframe_remove)
+            && flythrough.spline.keyframes.pop().is_some()
+        {
+            event!(
+                Level::INFO,
+                event = "debug_camera_keyframe_removed",
+                keyframes = flythrough.spline.keyframes.len(),
+            );
+        } else if keys.just_pressed(keyboard_bindings.play_flythrough) {
+            match flythrough.time {
+                Some(_) => flythrough.stop(),
+                None => flythrough.play(),
+            }
+        }
+    }
+
+    let Some(elapsed) = flythrough.time else {
+        return;
+    };
+    let Some(pose) = flythrough.spline.sample(elapsed) else {
+        flythrough.stop();
+        return;
+    };
+    for mut controlled_camera in q.iter_mut() {
+        controlled_camera.place(pose);
     }
+    // Open splines stop once the end has been shown
+    flythrough.time = if flythrough.spline.closed || elapsed < flythrough.spline.duration() {
+        Some(elapsed + time.delta_seconds())
+    } else {
+        None
+    };
 }
 
 /// This system is responsible for updating the camera's transform according to the [`DebugCamera`]
-/// component. When both control methods are off, this system stops updating, letting you control
-/// the camera independently (though we recommend removing the component entirely if you want to
+/// component. Cameras in [`DebugCameraMode::Ortho2d`] keep the depth of their current transform.
+/// When both control methods are off, this system stops updating, letting you control the camera
+/// independently (though we recommend removing the component entirely if you want to
 /// take over).
 pub fn camera_update_system(
     mut q: Query<(&mut Transform, &DebugCamera), With<Camera>>,
@@ -179,24 +1633,41 @@ pub fn camera_update_system(
 
     if debug_camera_active.gamepad || debug_camera_active.keymouse {
         for (mut transform, controlled_camera) in q.iter_mut() {
-            *transform = Transform::from_translation(controlled_camera.position).looking_at(
-                controlled_camera.position + controlled_camera.fwd,
-                controlled_camera.up,
-            );
+            // 2D cameras keep the depth they were spawned at (see `Camera2dBundle::new_with_far`),
+            // which keeps sprites in view
+            let mut position = controlled_camera.position;
+            if controlled_camera.mode == DebugCameraMode::Ortho2d {
+                position.z = transform.translation.z;
+            }
+            *transform = Transform::from_translation(position)
+                .looking_at(position + controlled_camera.fwd, controlled_camera.up);
         }
     }
 }
 
-/// This system ensures we're always locking the cursor in on the screen when running. We
-/// stop running this logic if keymouse input is off, letting you change the cursor mode.
+/// This system ensures we're always locking the cursor in on the screen when running. With the
+/// editor navigation scheme, or when any camera is in a mode that uses the cursor, the cursor is
+/// only locked while one of the mouse navigation buttons is held down. We stop running this logic
+/// if keymouse input is off, letting you change the cursor mode.
 pub fn cursor_grab_system(
     mut windows: Query<&mut Window>,
+    cameras: Query<&DebugCamera>,
     debug_camera_active: Res<DebugCameraActive>,
+    navigation_scheme: Res<NavigationScheme>,
+    mouse_bindings: Res<MouseBindings>,
+    mouse_buttons: Res<Input<MouseButton>>,
 ) {
-
     if debug_camera_active.keymouse {
         if let Some(mut window) = windows.iter_mut().next() {
-            if !debug_camera_active.esc_toggled {
+            let free_cursor = *navigation_scheme == NavigationScheme::Editor
+                || cameras.iter().any(|camera| camera.mode.uses_cursor());
+            let navigating = !free_cursor
+                || mouse_buttons.any_pressed([
+                    mouse_bindings.look,
+                    mouse_bindings.orbit,
+                    mouse_bindings.pan,
+                ]);
+            if !debug_camera_active.esc_toggled && navigating {
                 window.cursor.grab_mode = CursorGrabMode::Locked;
                 window.cursor.visible = false;
             } else {
@@ -267,3 +1738,339 @@ fn buttons_to_dir(positive: bool, negative: bool) -> f32 {
         -1.
     }
 }
+
+/// Movement input from the gamepad or keyboard, shared by all camera modes. Translation and
+/// rotation are scaled by time, and are in the camera's frame of reference, using the same axes as
+/// [`camera_movement_system`]: x = fwd, y = up, z = right for translation, and x = yaw, y = pitch,
+/// z = roll for rotation. Each mode maps these onto its own controls.
+#[derive(Debug, Clone, Copy, Default)]
+struct MovementInput {
+    translate: Vec3,
+    rotate: Vec3,
+    boost: bool,
+    precision: bool,
+}
+
+impl std::ops::AddAssign for MovementInput {
+    fn add_assign(&mut self, other: MovementInput) {
+        self.translate += other.translate;
+        self.rotate += other.rotate;
+        self.boost |= other.boost;
+        self.precision |= other.precision;
+    }
+}
+
+/// Reads the movement input of `gamepad` according to `bindings`.
+fn gamepad_movement_input(
+    gamepad: Gamepad,
+    delta_seconds: f32,
+    bindings: &GamepadBindings,
+    axes: &Axis<GamepadAxis>,
+    buttons: &Input<GamepadButton>,
+    button_axes: &Axis<GamepadButton>,
+) -> MovementInput {
+    let mut input = MovementInput::default();
+    if let (Some(x), Some(y), Some(down), Some(up)) = (
+        axes.get(GamepadAxis::new(gamepad, bindings.left_right)),
+        axes.get(GamepadAxis::new(gamepad, bindings.fwd_bwd)),
+        button_axes.get(GamepadButton::new(gamepad, bindings.down)),
+        button_axes.get(GamepadButton::new(gamepad, bindings.up)),
+    ) {
+        input.translate = delta_seconds * Vec3::new(y, up - down, x);
+    }
+    if let (Some(x), Some(y)) = (
+        axes.get(GamepadAxis::new(gamepad, bindings.yaw)),
+        axes.get(GamepadAxis::new(gamepad, bindings.pitch)),
+    ) {
+        input.rotate = delta_seconds * Vec3::new(-x, y, 0.);
+    }
+    input.rotate.z = delta_seconds
+        * buttons_to_dir(
+            buttons.pressed(GamepadButton::new(gamepad, bindings.roll_right)),
+            buttons.pressed(GamepadButton::new(gamepad, bindings.roll_left)),
+        );
+    input.boost = buttons.pressed(GamepadButton::new(gamepad, bindings.boost));
+    input.precision = buttons.pressed(GamepadButton::new(gamepad, bindings.precision));
+    input
+}
+
+/// Reads the movement input of the keyboard according to `bindings`.
+fn keyboard_movement_input(
+    delta_seconds: f32,
+    bindings: &KeyboardBindings,
+    keys: &Input<KeyCode>,
+) -> MovementInput {
+    let dir = |positive, negative| buttons_to_dir(keys.pressed(positive), keys.pressed(negative));
+    MovementInput {
+        // All keyboard input is multiplied by 0.5, as otherwise it will go too fast compared with
+        // controller
+        translate: delta_seconds
+            * 0.5
+            * Vec3::new(
+                dir(bindings.fwd, bindings.bwd),
+                dir(bindings.up, bindings.down),
+                dir(bindings.right, bindings.left),
+            ),
+        rotate: delta_seconds
+            * 0.5
+            * Vec3::new(0., 0., dir(bindings.roll_right, bindings.roll_left)),
+        boost: keys.pressed(bindings.boost),
+        precision: keys.pressed(bindings.precision),
+    }
+}
+
+/// The multiplier applied to `camera`'s speeds while the boost and precision modifiers are held.
+fn speed_modifier(camera: &DebugCamera, boost: bool, precision: bool) -> f32 {
+    let mut modifier = 1.;
+    if boost {
+        modifier *= camera.boost_multiplier;
+    }
+    if precision {
+        modifier *= camera.precision_multiplier;
+    }
+    modifier
+}
+
+/// Corrects the camera's basis vectors to be unit vectors that are perpendicular to each other.
+/// Returns the camera's right vector.
+fn orthonormalize(camera: &mut DebugCamera) -> Vec3 {
+    let right = camera.fwd.cross(camera.up);
+    camera.up = right.cross(camera.fwd);
+    camera.fwd = camera.up.cross(right);
+    camera.up = camera.up.normalize();
+    camera.fwd = camera.fwd.normalize();
+    camera.fwd.cross(camera.up)
+}
+
+/// Rotates the camera's basis vectors in place around its own position. `rotation` is given in
+/// radians in the camera's frame of reference (x = yaw, y = pitch, z = roll). `right` must be the
+/// camera's current right vector.
+fn rotate(camera: &mut DebugCamera, mut right: Vec3, rotation: Vec3) {
+    // Walking with a rolled camera makes little sense, so walking cameras are always locked. On the
+    // other hand, arcball cameras are meant to rotate freely.
+    let locked = match camera.mode {
+        DebugCameraMode::Walk => true,
+        DebugCameraMode::Arcball => false,
+        _ => camera.horizon_lock,
+    };
+    if locked {
+        rotate_horizon_locked(camera, rotation);
+        return;
+    }
+
+    // Rotation is applied on each direction individually for simplicity
+    // x rotation is relative to the up vector. Should keep both vectors perpendicular
+    let x_rot_quat = Quat::from_axis_angle(camera.up, rotation.x);
+    camera.fwd = x_rot_quat * camera.fwd;
+    right = x_rot_quat * right;
+    // y rotation is done by the right axis, which we just updated to rotate both fwd and up.
+    // Both are still perpendicular and unit vectors, so we don't need to normalise the result.
+    let y_rot_quat = Quat::from_axis_angle(right, rotation.y);
+    camera.fwd = y_rot_quat * camera.fwd;
+    camera.up = y_rot_quat * camera.up;
+    // lastly, z rotation is done relative to the fwd vector.
+    let z_rot_quat = Quat::from_axis_angle(camera.fwd, rotation.z);
+    camera.up = z_rot_quat * camera.up;
+}
+
+/// Rotates a horizon-locked camera in place around its own position. Yaw is applied around the
+/// world's up axis and pitch is clamped to [`HORIZON_LOCK_MAX_PITCH`], while roll is ignored and
+/// removed from the camera entirely. Arguments are the same as for [`rotate`].
+fn rotate_horizon_locked(camera: &mut DebugCamera, rotation: Vec3) {
+    let fwd = Quat::from_axis_angle(Vec3::Y, rotation.x) * camera.fwd;
+
+    // We split fwd into its heading on the horizontal plane and its pitch, and rebuild it from
+    // those. If we're looking straight up or down, the heading is opposite to our up vector.
+    let pitch = fwd.y.clamp(-1., 1.).asin();
+    let heading = Vec3::new(fwd.x, 0., fwd.z)
+        .try_normalize()
+        .or_else(|| (Vec3::new(camera.up.x, 0., camera.up.z) * -fwd.y.signum()).try_normalize())
+        .unwrap_or(Vec3::X);
+    let pitch = (pitch + rotation.y).clamp(-HORIZON_LOCK_MAX_PITCH, HORIZON_LOCK_MAX_PITCH);
+    camera.fwd = heading * pitch.cos() + Vec3::Y * pitch.sin();
+
+    // With no roll, up always lies on the plane formed by fwd and the world's up axis
+    let right = camera.fwd.cross(Vec3::Y).normalize();
+    camera.up = right.cross(camera.fwd);
+}
+
+/// Smooths out this frame's translation (in world space) and rotation (in radians, in the camera's
+/// frame of reference) according to the camera's inertia settings, returning the values that
+/// should be applied instead. This also keeps the camera's velocities up to date, even when it has
+/// no inertia, so that enabling it mid-flight is seamless.
+fn apply_inertia(
+    camera: &mut DebugCamera,
+    translation: Vec3,
+    rotation: Vec3,
+    delta_seconds: f32,
+) -> (Vec3, Vec3) {
+    if delta_seconds <= 0. {
+        return (translation, rotation);
+    }
+
+    let target_velocity = translation / delta_seconds;
+    let target_angular_velocity = rotation / delta_seconds;
+    match camera.inertia {
+        None => {
+            camera.velocity = target_velocity;
+            camera.angular_velocity = target_angular_velocity;
+        }
+        Some(inertia) => {
+            // Velocities decay exponentially towards their targets, which keeps the motion
+            // independent of frame rate. We accelerate while there's input, and damp otherwise.
+            let rate = if target_velocity == Vec3::ZERO {
+                inertia.damping
+            } else {
+                inertia.acceleration
+            };
+            camera.velocity = camera
+                .velocity
+                .lerp(target_velocity, 1. - (-rate * delta_seconds).exp());
+            camera.angular_velocity = camera.angular_velocity.lerp(
+                target_angular_velocity,
+                1. - (-inertia.rotation_smoothing * delta_seconds).exp(),
+            );
+        }
+    }
+
+    (
+        camera.velocity * delta_seconds,
+        camera.angular_velocity * delta_seconds,
+    )
+}
+
+/// Multiplies the camera's translation speed by its speed step once per step (dividing for negative
+/// steps), keeping it within the camera's configured speed range.
+fn change_speed(camera: &mut DebugCamera, steps: f32) {
+    if steps != 0. {
+        camera.speed_translate = (camera.speed_translate * camera.speed_translate_step.powf(steps))
+            .clamp(camera.speed_translate_min, camera.speed_translate_max);
+    }
+}
+
+/// Rotates the camera around its focus point, keeping the focus point fixed in place. Arguments
+/// are the same as for [`rotate`].
+fn orbit(camera: &mut DebugCamera, right: Vec3, rotation: Vec3) {
+    // We rotate the camera's basis exactly as we would when flying, and then place the camera back
+    // on the orbit so that it's facing the same focus point.
+    let focus = camera.focus();
+    rotate(camera, right, rotation);
+    camera.position = focus - camera.fwd * camera.focus_distance;
+}
+
+/// Moves the camera towards (positive `zoom`) or away from (negative `zoom`) its focus point,
+/// keeping the focus point fixed in place. The focus distance is scaled by `e^-zoom`.
+fn zoom_to_focus(camera: &mut DebugCamera, zoom: f32) {
+    let focus = camera.focus();
+    camera.focus_distance = (camera.focus_distance * (-zoom).exp()).max(MIN_FOCUS_DISTANCE);
+    camera.position = focus - camera.fwd * camera.focus_distance;
+}
+
+/// Returns the distance at which a sphere of the given radius fits entirely in view of a camera
+/// with the given projection. Cameras without a perspective projection are treated as having
+/// bevy's default field of view.
+fn framing_distance(radius: f32, projection: Option<&Projection>) -> f32 {
+    let (fov, aspect_ratio) = match projection {
+        Some(Projection::Perspective(perspective)) => (perspective.fov, perspective.aspect_ratio),
+        _ => (PerspectiveProjection::default().fov, 1.),
+    };
+    // The narrowest of the vertical and horizontal field of view is the one that limits us
+    let half_fov = fov / 2.;
+    let half_fov = half_fov.min((half_fov.tan() * aspect_ratio).atan());
+    radius / half_fov.sin()
+}
+
+/// Returns the closest position to `position` where a sphere of radius `radius` no longer
+/// overlaps `collider`, or `position` itself if they don't overlap.
+fn push_out_of_collider(
+    position: Vec3,
+    radius: f32,
+    collider: &DebugCameraCollider,
+    transform: &GlobalTransform,
+) -> Vec3 {
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    // We do all calculations in the collider's frame of reference, where it sits at the origin
+    let local = rotation.inverse() * (position - translation);
+
+    let pushed = match *collider {
+        DebugCameraCollider::Sphere {
+            radius: collider_radius,
+        } => {
+            let min_distance = collider_radius * scale.abs().max_element() + radius;
+            if local.length() >= min_distance {
+                return position;
+            }
+            // If we're right at the center any direction will do, so we pick up
+            local.try_normalize().unwrap_or(Vec3::Y) * min_distance
+        }
+        DebugCameraCollider::Box { half_extents } => {
+            let half_extents = half_extents * scale.abs();
+            let closest = local.clamp(-half_extents, half_extents);
+            let offset = local - closest;
+            if offset != Vec3::ZERO {
+                // Outside the box, so we push away from the closest point on its surface
+                if offset.length() >= radius {
+                    return position;
+                }
+                closest + offset.normalize() * radius
+            } else {
+                // Inside the box, so we leave through the nearest face
+                let depth = half_extents - local.abs();
+                let axis = if depth.x <= depth.y && depth.x <= depth.z {
+                    Vec3::X
+                } else if depth.y <= depth.z {
+                    Vec3::Y
+                } else {
+                    Vec3::Z
+                };
+                let sign = if local.dot(axis) < 0. { -1. } else { 1. };
+                local + axis * sign * (depth.dot(axis) + radius)
+            }
+        }
+    };
+
+    translation + rotation * pushed
+}
+
+/// Projects a cursor position onto the arcball, a unit sphere filling as much of the window as
+/// possible. Returns a point in the camera's frame of reference (x right, y up, z towards the
+/// viewer). Cursors outside the sphere are projected onto its edge, which is what lets the arcball
+/// roll.
+fn arcball_point(cursor: Vec2, window: &Window) -> Vec3 {
+    let size = Vec2::new(window.width(), window.height());
+    let radius = size.min_element() / 2.;
+    // Window coordinates have y pointing down
+    let point = (cursor - size / 2.) * Vec2::new(1., -1.) / radius;
+    let length_squared = point.length_squared();
+    if length_squared <= 1. {
+        point.extend((1. - length_squared).sqrt())
+    } else {
+        (point / length_squared.sqrt()).extend(0.)
+    }
+}
+
+/// Whether the mouse wheel should change the field of view zoom's magnification, rather than
+/// whatever it normally does.
+fn fov_zoom_scrolling(
+    navigation_scheme: &NavigationScheme,
+    mouse_buttons: &Input<MouseButton>,
+    mouse_bindings: &MouseBindings,
+) -> bool {
+    *navigation_scheme == NavigationScheme::Fly && mouse_buttons.pressed(mouse_bindings.fov_zoom)
+}
+
+/// Returns the near and far clip planes of a projection.
+fn clip_planes_of(projection: &Projection) -> (f32, f32) {
+    match projection {
+        Projection::Perspective(perspective) => (perspective.near, perspective.far),
+        Projection::Orthographic(orthographic) => (orthographic.near, orthographic.far),
+    }
+}
+
+/// Converts a mouse wheel event into a number of scrolled lines.
+fn scroll_lines(ev: &MouseWheel) -> f32 {
+    match ev.unit {
+        MouseScrollUnit::Line => ev.y,
+        MouseScrollUnit::Pixel => ev.y / PIXELS_PER_SCROLL_LINE,
+    }
+}
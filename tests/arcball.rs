@@ -0,0 +1,90 @@
+//! Checks that dragging the cursor rotates arcball cameras around their focus point.
+
+mod common;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_debug_camera::{DebugCamera, DebugCameraMode, DebugCameraPose};
+
+/// Builds an app with a 1280x720 primary window and an arcball camera at the origin, looking at
+/// `(5, 0, 0)`. Returns the app, window and camera.
+fn arcball_app() -> (App, Entity, Entity) {
+    let mut app = common::app();
+    let window = common::spawn(&mut app, (Window::default(), PrimaryWindow));
+    let camera = common::spawn(
+        &mut app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                mode: DebugCameraMode::Arcball,
+                ..default()
+            },
+        ),
+    );
+    common::start(&mut app, 1. / 60.);
+    (app, window, camera)
+}
+
+/// Drags the cursor along `path` with the arcball button held down, one point per update.
+fn drag(app: &mut App, window: Entity, path: &[Vec2]) {
+    common::press_mouse(app, MouseButton::Left);
+    for &cursor in path {
+        let mut window = app.world.get_mut::<Window>(window).unwrap();
+        window.set_cursor_position(Some(cursor));
+        app.update();
+    }
+    common::release_mouse(app, MouseButton::Left);
+    app.update();
+}
+
+#[test]
+fn dragging_rotates_around_the_focus_point() {
+    let (mut app, window, camera) = arcball_app();
+    drag(
+        &mut app,
+        window,
+        &[Vec2::new(640., 360.), Vec2::new(740., 360.)],
+    );
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), Vec3::new(5., 0., 0.));
+    common::assert_near(controlled_camera.focus_distance, 5.);
+    // Dragging sideways turns the camera around its up vector
+    common::assert_vec_near(controlled_camera.up, Vec3::Y);
+    assert!(controlled_camera.fwd.angle_between(Vec3::X) > 0.1);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, controlled_camera.position);
+    common::assert_vec_near(transform.forward(), controlled_camera.fwd);
+}
+
+#[test]
+fn dragging_around_the_edge_rolls() {
+    let (mut app, window, camera) = arcball_app();
+    // Quarter of a turn around the edge of the arcball, from its right to its top
+    let path: Vec<_> = (0..=10)
+        .map(|step| {
+            let angle = step as f32 / 10. * std::f32::consts::FRAC_PI_2;
+            Vec2::new(640., 360.) + 360. * Vec2::new(angle.cos(), -angle.sin())
+        })
+        .collect();
+    drag(&mut app, window, &path);
+
+    let controlled_camera = common::camera(&app, camera);
+    // The scene turns with the cursor, so the camera's up vector ends up where its right was
+    let expected = DebugCameraPose {
+        position: Vec3::ZERO,
+        fwd: Vec3::X,
+        up: Vec3::Z,
+    };
+    common::assert_same_pose(controlled_camera.pose(), expected, 1e-3);
+}
+
+#[test]
+fn mouse_motion_does_not_look_around() {
+    let (mut app, _, camera) = arcball_app();
+    common::move_mouse(&mut app, Vec2::new(200., 100.));
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.position, Vec3::ZERO);
+    common::assert_vec_near(controlled_camera.fwd, Vec3::X);
+}
@@ -0,0 +1,62 @@
+//! Checks that bookmarks save the camera's pose and bring it back there later.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraBookmarks};
+
+#[test]
+fn recalls_saved_poses() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (Camera3dBundle::default(), DebugCamera::default()),
+    );
+    common::start(&mut app, 1. / 60.);
+    common::move_mouse(&mut app, Vec2::new(100., 50.));
+    app.update();
+    let saved = common::camera(&app, camera).pose();
+
+    common::press(&mut app, KeyCode::ControlLeft);
+    common::tap(&mut app, KeyCode::Key1);
+    common::release(&mut app, KeyCode::ControlLeft);
+    let bookmark = app.world.resource::<DebugCameraBookmarks>().get(0);
+    common::assert_same_pose(bookmark.expect("nothing was saved"), saved, 1e-4);
+
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 60);
+    common::release(&mut app, KeyCode::W);
+    app.update();
+    assert!(
+        common::camera(&app, camera)
+            .position
+            .distance(saved.position)
+            > 1.
+    );
+
+    // The camera transitions back over half a second
+    common::tap(&mut app, KeyCode::Key1);
+    assert!(common::camera(&app, camera).transition.is_some());
+    common::run(&mut app, 30);
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_same_pose(controlled_camera.pose(), saved, 1e-4);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, saved.position);
+    common::assert_vec_near(transform.forward(), saved.fwd);
+}
+
+#[test]
+fn empty_slots_are_ignored() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    common::start(&mut app, 1. / 60.);
+    common::tap(&mut app, KeyCode::Key2);
+
+    let controlled_camera = common::camera(&app, camera);
+    assert!(controlled_camera.transition.is_none());
+    common::assert_same_pose(
+        controlled_camera.pose(),
+        DebugCamera::default().pose(),
+        1e-4,
+    );
+}
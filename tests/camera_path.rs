@@ -0,0 +1,233 @@
+//! Checks that recorded camera paths and keyframe splines are sampled as expected, regardless of
+//! frame timing, and that cameras are moved along them.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{
+    DebugCamera, DebugCameraFlythrough, DebugCameraKeyframe, DebugCameraPath,
+    DebugCameraPathRecorder, DebugCameraPathSample, DebugCameraPathState, DebugCameraPose,
+    DebugCameraSpline,
+};
+
+fn pose_at(x: f32) -> DebugCameraPose {
+    DebugCameraPose {
+        position: Vec3::new(x, 0., 0.),
+        fwd: Vec3::NEG_Z,
+        up: Vec3::Y,
+    }
+}
+
+fn path() -> DebugCameraPath {
+    DebugCameraPath {
+        samples: vec![
+            DebugCameraPathSample {
+                time: 0.,
+                pose: pose_at(0.),
+            },
+            DebugCameraPathSample {
+                time: 1.,
+                pose: pose_at(10.),
+            },
+            DebugCameraPathSample {
+                time: 3.,
+                pose: pose_at(30.),
+            },
+        ],
+    }
+}
+
+#[test]
+fn sample_interpolates() {
+    let path = path();
+    assert_eq!(path.duration(), 3.);
+    for (time, x) in [(0., 0.), (0.5, 5.), (1., 10.), (2., 20.), (3., 30.)] {
+        let pose = path.sample(time).unwrap();
+        assert!((pose.position.x - x).abs() < 1e-4, "at {time}: {pose:?}");
+    }
+}
+
+#[test]
+fn sample_clamps_to_ends() {
+    let path = path();
+    assert_eq!(path.sample(-1.).unwrap().position.x, 0.);
+    assert_eq!(path.sample(5.).unwrap().position.x, 30.);
+    assert_eq!(DebugCameraPath::default().sample(0.), None);
+}
+
+/// Builds an app with a camera that has recorded itself flying forwards and turning for a second.
+fn recorded_app() -> (App, Entity) {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (Camera3dBundle::default(), DebugCamera::default()),
+    );
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::F9);
+    common::press(&mut app, KeyCode::W);
+    common::press(&mut app, KeyCode::E);
+    app.update();
+    common::release(&mut app, KeyCode::F9);
+    common::run(&mut app, 59);
+    common::release(&mut app, KeyCode::W);
+    common::release(&mut app, KeyCode::E);
+    common::tap(&mut app, KeyCode::F9);
+    (app, camera)
+}
+
+#[test]
+fn records_the_flight() {
+    let (app, camera) = recorded_app();
+    let recorder = app.world.resource::<DebugCameraPathRecorder>();
+    assert_eq!(recorder.state, DebugCameraPathState::Idle);
+    let samples = &recorder.path.samples;
+    // One sample per frame, until the frame recording was stopped on
+    assert_eq!(samples.len(), 60);
+    assert!((recorder.path.duration() - 59. / 60.).abs() < 1e-4);
+    // The camera stopped moving on that frame too, so it's still at the last sample
+    common::assert_same_pose(
+        samples.last().unwrap().pose,
+        common::camera(&app, camera).pose(),
+        1e-4,
+    );
+    assert!(samples[0].pose.position.x < 0.1 && samples[59].pose.position.x > 4.9);
+}
+
+#[test]
+fn plays_back_the_recording() {
+    let (mut app, camera) = recorded_app();
+    let path = app.world.resource::<DebugCameraPathRecorder>().path.clone();
+    app.world
+        .get_mut::<DebugCamera>(camera)
+        .unwrap()
+        .set_pose(pose_at(100.));
+
+    // Playback follows the recorded timeline, even at a different frame rate
+    common::start(&mut app, 1. / 25.);
+    common::press(&mut app, KeyCode::F10);
+    app.update();
+    common::assert_same_pose(
+        common::camera(&app, camera).pose(),
+        path.samples[0].pose,
+        1e-4,
+    );
+    common::run(&mut app, 10);
+    let expected = path.sample(0.4).unwrap();
+    common::assert_same_pose(common::camera(&app, camera).pose(), expected, 1e-3);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, expected.position);
+
+    // Once the end is reached, the camera stays there
+    common::run(&mut app, 30);
+    let recorder = app.world.resource::<DebugCameraPathRecorder>();
+    assert_eq!(recorder.state, DebugCameraPathState::Idle);
+    let end = path.samples.last().unwrap().pose;
+    common::assert_same_pose(common::camera(&app, camera).pose(), end, 1e-4);
+}
+
+#[test]
+fn looping_playback_starts_over() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    app.insert_resource(DebugCameraPathRecorder {
+        path: path(),
+        looping: true,
+        speed: 2.,
+        ..default()
+    });
+    common::start(&mut app, 1. / 10.);
+    app.world.resource_mut::<DebugCameraPathRecorder>().play();
+    // Playing at double speed, 2 seconds in is 4 seconds into the 3 second path
+    common::run(&mut app, 21);
+
+    let recorder = app.world.resource::<DebugCameraPathRecorder>();
+    assert!(matches!(
+        recorder.state,
+        DebugCameraPathState::Playing { .. }
+    ));
+    let pose = common::camera(&app, camera).pose();
+    assert!((pose.position.x - 10.).abs() < 1e-3, "{pose:?}");
+}
+
+fn spline(closed: bool) -> DebugCameraSpline {
+    let keyframes = [(0., 0.), (10., 1.), (20., 3.)]
+        .map(|(x, duration)| DebugCameraKeyframe {
+            pose: pose_at(x),
+            duration,
+        })
+        .to_vec();
+    DebugCameraSpline { keyframes, closed }
+}
+
+#[test]
+fn spline_passes_through_keyframes() {
+    let spline = spline(false);
+    assert_eq!(spline.duration(), 4.);
+    for (time, x) in [(0., 0.), (1., 10.), (4., 20.), (9., 20.)] {
+        let pose = spline.sample(time).unwrap();
+        assert!((pose.position.x - x).abs() < 1e-4, "at {time}: {pose:?}");
+    }
+}
+
+#[test]
+fn closed_spline_wraps() {
+    let spline = spline(true);
+    assert_eq!(spline.duration(), 4.);
+    let start = spline.sample(0.).unwrap();
+    let looped = spline.sample(4.).unwrap();
+    assert!(start.position.abs_diff_eq(looped.position, 1e-4));
+    let pose = spline.sample(5.).unwrap();
+    assert!((pose.position.x - 10.).abs() < 1e-4, "{pose:?}");
+}
+
+/// Moves `camera` to `pose` and drops a keyframe there.
+fn add_keyframe(app: &mut App, camera: Entity, pose: DebugCameraPose) {
+    app.world
+        .get_mut::<DebugCamera>(camera)
+        .unwrap()
+        .set_pose(pose);
+    common::tap(app, KeyCode::K);
+}
+
+#[test]
+fn flythrough_passes_through_dropped_keyframes() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (Camera3dBundle::default(), DebugCamera::default()),
+    );
+    common::start(&mut app, 1. / 60.);
+    let turned = DebugCameraPose {
+        fwd: Vec3::X,
+        ..pose_at(20.)
+    };
+    let keyframes = [pose_at(0.), pose_at(10.), turned];
+    for pose in keyframes {
+        add_keyframe(&mut app, camera, pose);
+    }
+    // A stray keyframe, removed straight away
+    add_keyframe(&mut app, camera, pose_at(30.));
+    common::tap(&mut app, KeyCode::Back);
+    let spline = app.world.resource::<DebugCameraFlythrough>().spline.clone();
+    assert_eq!(spline.keyframes.len(), 3);
+    assert_eq!(spline.duration(), 4.);
+
+    // The first frame shows the start of the flythrough, and each one after that is a sixtieth of
+    // a second further in
+    common::press(&mut app, KeyCode::F8);
+    app.update();
+    common::assert_same_pose(common::camera(&app, camera).pose(), keyframes[0], 1e-4);
+    common::run(&mut app, 60);
+    let halfway = spline.sample(1.).unwrap();
+    common::assert_same_pose(common::camera(&app, camera).pose(), halfway, 1e-3);
+    common::run(&mut app, 60);
+    common::assert_same_pose(common::camera(&app, camera).pose(), keyframes[1], 1e-3);
+
+    // It stops at the end, facing the way the last keyframe does
+    common::run(&mut app, 150);
+    assert_eq!(app.world.resource::<DebugCameraFlythrough>().time, None);
+    common::assert_same_pose(common::camera(&app, camera).pose(), turned, 1e-4);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, turned.position);
+    common::assert_vec_near(transform.forward(), Vec3::X);
+}
@@ -0,0 +1,44 @@
+//! Checks that adjusting the clip planes never pushes one past the other.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraClipPlanes, KeyboardBindings};
+
+/// Holds `key` for ten seconds with a default perspective camera, and returns its near and far
+/// planes.
+fn hold(key: impl Fn(&KeyboardBindings) -> KeyCode) -> (f32, f32) {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, (DebugCamera::default(), Projection::default()));
+    common::start(&mut app, 0.1);
+    let key = key(app.world.resource::<KeyboardBindings>());
+    common::press(&mut app, key);
+    common::run(&mut app, 100);
+
+    let Projection::Perspective(projection) = app.world.get::<Projection>(camera).unwrap() else {
+        unreachable!();
+    };
+    (projection.near, projection.far)
+}
+
+#[test]
+fn near_stays_in_front_of_far() {
+    let (near, far) = hold(|bindings| bindings.near_plane_out);
+    let min_gap = DebugCameraClipPlanes::default().min_gap;
+    assert_eq!(far, PerspectiveProjection::default().far);
+    assert!(
+        (far - near - min_gap).abs() < 1e-3,
+        "near {near}, far {far}"
+    );
+}
+
+#[test]
+fn far_stays_behind_near() {
+    let (near, far) = hold(|bindings| bindings.far_plane_in);
+    let min_gap = DebugCameraClipPlanes::default().min_gap;
+    assert_eq!(near, PerspectiveProjection::default().near);
+    assert!(
+        (far - near - min_gap).abs() < 1e-3,
+        "near {near}, far {far}"
+    );
+}
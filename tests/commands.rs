@@ -0,0 +1,98 @@
+//! Checks that commands leave cameras with a valid orientation, exactly where they were sent.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{
+    DebugCamera, DebugCameraAction, DebugCameraCommand, DebugCameraInertia, DebugCameraPose,
+};
+
+/// Sends `action` to a default camera placed at `position`, and returns the camera's pose
+/// afterwards, along with whether it started a transition.
+fn command(position: Vec3, action: DebugCameraAction) -> (DebugCameraPose, bool) {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            position,
+            ..default()
+        },
+    );
+    app.world.send_event(DebugCameraCommand {
+        action,
+        camera: None,
+    });
+    app.update();
+    let camera = common::camera(&app, camera);
+    (camera.pose(), camera.transition.is_some())
+}
+
+#[test]
+fn look_at_point_straight_below() {
+    let (pose, _) = command(
+        Vec3::new(0., 10., 0.),
+        DebugCameraAction::LookAt(Vec3::ZERO),
+    );
+    assert!(pose.is_valid(), "{pose:?}");
+    assert!(pose.fwd.abs_diff_eq(Vec3::NEG_Y, 1e-4), "{pose:?}");
+}
+
+#[test]
+fn invalid_teleports_are_ignored() {
+    let position = Vec3::new(1., 2., 3.);
+    let unchanged = DebugCamera {
+        position,
+        ..default()
+    }
+    .pose();
+    let invalid = [
+        (Vec3::ZERO, Vec3::Y),
+        (Vec3::Y, Vec3::Y),
+        (Vec3::NEG_Z, Vec3::ZERO),
+        (Vec3::new(f32::NAN, 0., -1.), Vec3::Y),
+    ];
+    for (fwd, up) in invalid {
+        let pose = DebugCameraPose {
+            position: Vec3::ZERO,
+            fwd,
+            up,
+        };
+        assert!(!pose.is_valid());
+        for smooth in [false, true] {
+            let action = DebugCameraAction::Teleport { pose, smooth };
+            assert_eq!(command(position, action), (unchanged, false));
+        }
+    }
+}
+
+#[test]
+fn teleports_stop_inertia() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            inertia: Some(DebugCameraInertia::default()),
+            ..default()
+        },
+    );
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 30);
+    assert!(common::camera(&app, camera).velocity != Vec3::ZERO);
+
+    let pose = DebugCameraPose {
+        position: Vec3::new(0., 0., 10.),
+        fwd: Vec3::NEG_Z,
+        up: Vec3::Y,
+    };
+    common::release(&mut app, KeyCode::W);
+    app.world.send_event(DebugCameraCommand {
+        action: DebugCameraAction::Teleport {
+            pose,
+            smooth: false,
+        },
+        camera: None,
+    });
+    common::run(&mut app, 60);
+    common::assert_same_pose(common::camera(&app, camera).pose(), pose, 1e-4);
+}
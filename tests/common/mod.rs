@@ -0,0 +1,151 @@
+//! Fixtures shared by the integration tests, which drive the plugin in a headless app.
+
+// Each test only uses some of these
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_debug_camera::{DebugCamera, DebugCameraPlugin, DebugCameraPose};
+
+/// Builds a headless app running the [`DebugCameraPlugin`] with its default settings.
+pub fn app() -> App {
+    app_with(DebugCameraPlugin::default())
+}
+
+/// Builds a headless app running `plugin`.
+pub fn app_with(plugin: DebugCameraPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, plugin));
+    app
+}
+
+/// Makes every following frame of `app` last `frame_time` seconds, and runs its first update.
+pub fn start(app: &mut App, frame_time: f32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        frame_time,
+    )));
+    // The first update only initialises time
+    app.update();
+}
+
+/// Runs `frames` updates of `app`.
+pub fn run(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// Spawns an entity with `bundle`, such as a [`DebugCamera`], and returns it.
+pub fn spawn(app: &mut App, bundle: impl Bundle) -> Entity {
+    app.world.spawn(bundle).id()
+}
+
+/// The [`DebugCamera`] of `entity`.
+pub fn camera(app: &App, entity: Entity) -> &DebugCamera {
+    app.world.get::<DebugCamera>(entity).unwrap()
+}
+
+/// Presses `key` on the next update. It stays held until released.
+pub fn press(app: &mut App, key: KeyCode) {
+    send_key(app, key, ButtonState::Pressed);
+}
+
+/// Releases `key` on the next update.
+pub fn release(app: &mut App, key: KeyCode) {
+    send_key(app, key, ButtonState::Released);
+}
+
+/// Presses `key` for one update, and releases it on the next.
+pub fn tap(app: &mut App, key: KeyCode) {
+    press(app, key);
+    app.update();
+    release(app, key);
+    app.update();
+}
+
+fn send_key(app: &mut App, key: KeyCode, state: ButtonState) {
+    app.world.send_event(KeyboardInput {
+        scan_code: 0,
+        key_code: Some(key),
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Presses `button` on the next update. It stays held until released.
+pub fn press_mouse(app: &mut App, button: MouseButton) {
+    send_mouse_button(app, button, ButtonState::Pressed);
+}
+
+/// Releases `button` on the next update.
+pub fn release_mouse(app: &mut App, button: MouseButton) {
+    send_mouse_button(app, button, ButtonState::Released);
+}
+
+fn send_mouse_button(app: &mut App, button: MouseButton, state: ButtonState) {
+    app.world.send_event(MouseButtonInput {
+        button,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Moves the mouse by `delta` pixels on the next update.
+pub fn move_mouse(app: &mut App, delta: Vec2) {
+    app.world.send_event(MouseMotion { delta });
+}
+
+/// Scrolls the mouse wheel by `lines` on the next update.
+pub fn scroll(app: &mut App, lines: f32) {
+    app.world.send_event(MouseWheel {
+        unit: MouseScrollUnit::Line,
+        x: 0.,
+        y: lines,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+pub fn assert_near(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected}, got {actual}"
+    );
+}
+
+pub fn assert_vec_near(actual: Vec3, expected: Vec3) {
+    assert!(
+        actual.abs_diff_eq(expected, 1e-4),
+        "expected {expected}, got {actual}"
+    );
+}
+
+/// Asserts that every value of the two poses is within `tolerance` of each other.
+pub fn assert_same_pose(a: DebugCameraPose, b: DebugCameraPose, tolerance: f32) {
+    assert!(
+        a.position.abs_diff_eq(b.position, tolerance),
+        "position differs: {} vs {}",
+        a.position,
+        b.position
+    );
+    assert!(
+        a.fwd.abs_diff_eq(b.fwd, tolerance),
+        "fwd differs: {} vs {}",
+        a.fwd,
+        b.fwd
+    );
+    assert!(
+        a.up.abs_diff_eq(b.up, tolerance),
+        "up differs: {} vs {}",
+        a.up,
+        b.up
+    );
+}
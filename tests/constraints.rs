@@ -0,0 +1,105 @@
+//! Checks that cameras are pushed out of colliders, including rotated and scaled ones.
+
+mod common;
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraCollider, DebugCameraConstraints};
+use common::assert_vec_near;
+
+/// Runs a single frame with a camera at `position` and `collider` placed at `transform`, and
+/// returns where the camera ends up. The camera has the default collision radius of 0.2.
+fn push_out(position: Vec3, collider: DebugCameraCollider, transform: Transform) -> Vec3 {
+    let mut app = common::app();
+    common::spawn(&mut app, (collider, GlobalTransform::from(transform)));
+    let camera = common::spawn(
+        &mut app,
+        (
+            DebugCamera {
+                position,
+                ..default()
+            },
+            DebugCameraConstraints::default(),
+        ),
+    );
+    app.update();
+    common::camera(&app, camera).position
+}
+
+#[test]
+fn sphere() {
+    let sphere = DebugCameraCollider::Sphere { radius: 1. };
+    assert_vec_near(
+        push_out(Vec3::new(0.5, 0., 0.), sphere, Transform::IDENTITY),
+        Vec3::new(1.2, 0., 0.),
+    );
+    assert_vec_near(
+        push_out(
+            Vec3::new(0., 0., -2.),
+            sphere,
+            Transform::from_scale(Vec3::splat(2.)),
+        ),
+        Vec3::new(0., 0., -2.2),
+    );
+    // Cameras far enough away are left alone
+    assert_vec_near(
+        push_out(Vec3::new(1.5, 0., 0.), sphere, Transform::IDENTITY),
+        Vec3::new(1.5, 0., 0.),
+    );
+}
+
+#[test]
+fn box_outside() {
+    let cube = DebugCameraCollider::Box {
+        half_extents: Vec3::ONE,
+    };
+    // Within the collision radius of a face, and of an edge
+    assert_vec_near(
+        push_out(Vec3::new(1.1, 0.5, 0.), cube, Transform::IDENTITY),
+        Vec3::new(1.2, 0.5, 0.),
+    );
+    let pushed = push_out(Vec3::new(1.1, 1.1, 0.), cube, Transform::IDENTITY);
+    assert_vec_near(
+        pushed,
+        Vec3::new(1., 1., 0.) + Vec3::new(1., 1., 0.).normalize() * 0.2,
+    );
+    // Out of reach
+    assert_vec_near(
+        push_out(Vec3::new(1.5, 0., 0.), cube, Transform::IDENTITY),
+        Vec3::new(1.5, 0., 0.),
+    );
+}
+
+#[test]
+fn box_inside_leaves_through_nearest_face() {
+    let cube = DebugCameraCollider::Box {
+        half_extents: Vec3::ONE,
+    };
+    assert_vec_near(
+        push_out(Vec3::new(0.1, -0.8, 0.2), cube, Transform::IDENTITY),
+        Vec3::new(0.1, -1.2, 0.2),
+    );
+}
+
+#[test]
+fn box_rotated_and_scaled() {
+    let long = DebugCameraCollider::Box {
+        half_extents: Vec3::new(2., 1., 1.),
+    };
+    // Rotating the box a quarter turn lines its long side up with z
+    let rotated = Transform::from_rotation(Quat::from_rotation_y(FRAC_PI_2));
+    assert_vec_near(
+        push_out(Vec3::new(0., 0., 1.5), long, rotated),
+        Vec3::new(0., 0., 2.2),
+    );
+
+    let cube = DebugCameraCollider::Box {
+        half_extents: Vec3::ONE,
+    };
+    let scaled = Transform::from_xyz(5., 0., 0.).with_scale(Vec3::splat(2.));
+    assert_vec_near(
+        push_out(Vec3::new(5., 0., 2.1), cube, scaled),
+        Vec3::new(5., 0., 2.2),
+    );
+}
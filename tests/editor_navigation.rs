@@ -0,0 +1,84 @@
+//! Checks that the editor navigation scheme only moves cameras while a mouse button is held.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraPlugin, NavigationScheme};
+
+/// Builds an app using the editor scheme, with a camera at the origin looking at `(5, 0, 0)`.
+fn editor_app() -> (App, Entity) {
+    let mut app = common::app_with(DebugCameraPlugin {
+        navigation_scheme: NavigationScheme::Editor,
+        ..default()
+    });
+    let camera = common::spawn(
+        &mut app,
+        (Camera3dBundle::default(), DebugCamera::default()),
+    );
+    common::start(&mut app, 1. / 60.);
+    (app, camera)
+}
+
+#[test]
+fn mouse_does_nothing_without_buttons() {
+    let (mut app, camera) = editor_app();
+    common::move_mouse(&mut app, Vec2::new(200., 100.));
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 10);
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.position, Vec3::ZERO);
+    common::assert_vec_near(controlled_camera.fwd, Vec3::X);
+}
+
+#[test]
+fn orbit_button_orbits_around_the_focus_point() {
+    let (mut app, camera) = editor_app();
+    common::press_mouse(&mut app, MouseButton::Middle);
+    common::move_mouse(&mut app, Vec2::new(200., 100.));
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), Vec3::new(5., 0., 0.));
+    assert!(controlled_camera.fwd.angle_between(Vec3::X) > 0.5);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, controlled_camera.position);
+}
+
+#[test]
+fn pan_button_pans_against_the_drag() {
+    let (mut app, camera) = editor_app();
+    common::press(&mut app, KeyCode::ShiftLeft);
+    common::press_mouse(&mut app, MouseButton::Middle);
+    common::move_mouse(&mut app, Vec2::new(100., 0.));
+    app.update();
+
+    // Dragging right moves the camera left, by a distance proportional to the focus distance
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.position, Vec3::new(0., 0., -0.75));
+    common::assert_vec_near(controlled_camera.fwd, Vec3::X);
+    common::assert_near(controlled_camera.focus_distance, 5.);
+}
+
+#[test]
+fn scrolling_dollies_to_the_focus_point() {
+    let (mut app, camera) = editor_app();
+    common::scroll(&mut app, 3.);
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), Vec3::new(5., 0., 0.));
+    common::assert_near(controlled_camera.focus_distance, 5. * (-0.3f32).exp());
+    common::assert_near(controlled_camera.speed_translate, 10.);
+}
+
+#[test]
+fn look_button_enables_the_fly_controls() {
+    let (mut app, camera) = editor_app();
+    common::press_mouse(&mut app, MouseButton::Right);
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 60);
+
+    let controlled_camera = common::camera(&app, camera);
+    assert!((controlled_camera.position.x - 5.).abs() < 1e-3);
+}
@@ -0,0 +1,69 @@
+//! Checks how far back cameras are placed to frame an entity when focusing on it.
+
+mod common;
+
+use bevy::{prelude::*, render::primitives::Aabb};
+use bevy_debug_camera::{DebugCamera, DebugCameraFocus};
+use common::assert_near;
+
+/// Focuses a camera with `projection` on an entity whose bounding sphere has `radius`, and returns
+/// the resulting focus distance.
+fn focus_distance(radius: f32, projection: Option<Projection>) -> f32 {
+    let mut app = common::app();
+    let target = common::spawn(
+        &mut app,
+        (
+            GlobalTransform::from_xyz(0., 0., -10.),
+            Aabb::from_min_max(Vec3::new(-radius, 0., 0.), Vec3::new(radius, 0., 0.)),
+        ),
+    );
+    let mut camera = app.world.spawn(DebugCamera::default());
+    if let Some(projection) = projection {
+        camera.insert(projection);
+    }
+    let camera = camera.id();
+    app.world.send_event(DebugCameraFocus {
+        target,
+        camera: None,
+    });
+    app.update();
+    common::camera(&app, camera).focus_distance
+}
+
+fn perspective(aspect_ratio: f32) -> Option<Projection> {
+    Some(Projection::Perspective(PerspectiveProjection {
+        aspect_ratio,
+        ..default()
+    }))
+}
+
+#[test]
+fn scales_with_radius() {
+    assert_near(
+        focus_distance(2., perspective(1.)),
+        2. * focus_distance(1., perspective(1.)),
+    );
+}
+
+#[test]
+fn narrowest_field_of_view_limits_distance() {
+    let square = focus_distance(1., perspective(1.));
+    // Wide windows are limited by the vertical field of view, like square ones
+    assert_near(focus_distance(1., perspective(2.)), square);
+
+    // Tall windows are limited by the narrower horizontal field of view
+    let half_fov = PerspectiveProjection::default().fov / 2.;
+    let half_horizontal_fov = (half_fov.tan() * 0.5).atan();
+    assert_near(
+        focus_distance(1., perspective(0.5)),
+        square * half_fov.sin() / half_horizontal_fov.sin(),
+    );
+}
+
+#[test]
+fn defaults_without_perspective_projection() {
+    assert_near(
+        focus_distance(1., None),
+        focus_distance(1., perspective(1.)),
+    );
+}
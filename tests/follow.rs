@@ -0,0 +1,123 @@
+//! Checks that following cameras track their target, and stop once it's gone.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraFollow, DebugCameraMode, DebugCameraSelection};
+
+/// Spawns a target at `position`, facing -z. The app has no transform propagation, so we set its
+/// global transform directly.
+fn spawn_target(app: &mut App, position: Vec3) -> Entity {
+    common::spawn(
+        app,
+        GlobalTransform::from(Transform::from_translation(position)),
+    )
+}
+
+/// Spawns a camera following `target`, catching up with it over `lag` seconds.
+fn spawn_follower(app: &mut App, target: Entity, lag: f32) -> Entity {
+    common::spawn(
+        app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                mode: DebugCameraMode::Follow(target),
+                follow: DebugCameraFollow { lag, ..default() },
+                ..default()
+            },
+        ),
+    )
+}
+
+#[test]
+fn rigidly_attached_cameras_keep_their_offset() {
+    let mut app = common::app();
+    let target_position = Vec3::new(10., 0., 0.);
+    let target = spawn_target(&mut app, target_position);
+    let camera = spawn_follower(&mut app, target, 0.);
+    common::start(&mut app, 1. / 60.);
+
+    let expected_position = target_position + DebugCameraFollow::default().offset;
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.position, expected_position);
+    common::assert_vec_near(controlled_camera.focus(), target_position);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, expected_position);
+    common::assert_vec_near(
+        transform.forward(),
+        (target_position - expected_position).normalize(),
+    );
+
+    // Turning the target swings the camera around behind it
+    let rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+    *app.world.get_mut::<GlobalTransform>(target).unwrap() =
+        Transform::from_translation(target_position)
+            .with_rotation(rotation)
+            .into();
+    app.update();
+    common::assert_vec_near(
+        common::camera(&app, camera).position,
+        target_position + rotation * DebugCameraFollow::default().offset,
+    );
+}
+
+#[test]
+fn lagging_cameras_catch_up() {
+    let mut app = common::app();
+    let target = spawn_target(&mut app, Vec3::ZERO);
+    let camera = spawn_follower(&mut app, target, 0.2);
+    common::start(&mut app, 1. / 60.);
+    common::run(&mut app, 120);
+
+    let target_position = Vec3::new(10., 0., 0.);
+    *app.world.get_mut::<GlobalTransform>(target).unwrap() =
+        GlobalTransform::from_translation(target_position);
+    app.update();
+    let expected_position = target_position + DebugCameraFollow::default().offset;
+    let behind = common::camera(&app, camera).position;
+    assert!(behind.x > 0. && behind.x < 1., "{behind}");
+
+    common::run(&mut app, 120);
+    let caught_up = common::camera(&app, camera).position;
+    assert!(
+        caught_up.abs_diff_eq(expected_position, 1e-3),
+        "{caught_up}"
+    );
+}
+
+#[test]
+fn follow_binding_toggles_following_the_selection() {
+    let mut app = common::app();
+    let target = spawn_target(&mut app, Vec3::new(10., 0., 0.));
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    app.insert_resource(DebugCameraSelection(Some(target)));
+    common::start(&mut app, 1. / 60.);
+
+    common::press(&mut app, KeyCode::T);
+    app.update();
+    assert_eq!(
+        common::camera(&app, camera).mode,
+        DebugCameraMode::Follow(target)
+    );
+
+    common::release(&mut app, KeyCode::T);
+    app.update();
+    common::press(&mut app, KeyCode::T);
+    app.update();
+    assert_eq!(common::camera(&app, camera).mode, DebugCameraMode::Fly);
+}
+
+#[test]
+fn cameras_stop_following_despawned_targets() {
+    let mut app = common::app();
+    let target = spawn_target(&mut app, Vec3::new(10., 0., 0.));
+    let camera = spawn_follower(&mut app, target, 0.);
+    common::start(&mut app, 1. / 60.);
+    let position = common::camera(&app, camera).position;
+
+    app.world.despawn(target);
+    common::run(&mut app, 2);
+    let controlled_camera = common::camera(&app, camera);
+    assert_eq!(controlled_camera.mode, DebugCameraMode::Fly);
+    common::assert_vec_near(controlled_camera.position, position);
+}
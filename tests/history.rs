@@ -0,0 +1,69 @@
+//! Checks which poses the navigation history records as cameras move around.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{
+    DebugCamera, DebugCameraAction, DebugCameraCommand, DebugCameraHistory, DebugCameraPose,
+};
+
+#[test]
+fn instant_teleports_are_recorded() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    app.update();
+    let start = common::camera(&app, camera).pose();
+
+    let target = DebugCameraPose {
+        position: Vec3::new(100., 0., 0.),
+        ..start
+    };
+    app.world.send_event(DebugCameraCommand {
+        action: DebugCameraAction::Teleport {
+            pose: target,
+            smooth: false,
+        },
+        camera: None,
+    });
+    app.update();
+
+    let history = app.world.resource::<DebugCameraHistory>();
+    assert_eq!(history.poses().copied().collect::<Vec<_>>(), vec![start]);
+    assert_eq!(common::camera(&app, camera).pose(), target);
+}
+
+#[test]
+fn look_at_commands_are_recorded() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    app.update();
+    let start = common::camera(&app, camera).pose();
+
+    // Turning around on the spot is a jump too, even though the camera doesn't move
+    app.world.send_event(DebugCameraCommand {
+        action: DebugCameraAction::LookAt(Vec3::new(-10., 0., 0.)),
+        camera: None,
+    });
+    app.update();
+
+    let history = app.world.resource::<DebugCameraHistory>();
+    assert_eq!(history.poses().copied().collect::<Vec<_>>(), vec![start]);
+}
+
+#[test]
+fn fast_flight_is_not_recorded() {
+    let mut app = common::app();
+    common::spawn(
+        &mut app,
+        DebugCamera {
+            speed_translate: 1000.,
+            ..default()
+        },
+    );
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 60);
+
+    let history = app.world.resource::<DebugCameraHistory>();
+    assert_eq!(history.poses().count(), 0);
+}
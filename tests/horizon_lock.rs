@@ -0,0 +1,68 @@
+//! Checks that horizon-locked cameras never roll or flip over the poles.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::DebugCamera;
+
+fn locked_app() -> (App, Entity) {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                horizon_lock: true,
+                ..default()
+            },
+        ),
+    );
+    common::start(&mut app, 1. / 60.);
+    (app, camera)
+}
+
+/// Asserts that the camera's right vector is level with the horizon.
+fn assert_level(camera: &DebugCamera) {
+    let right = camera.fwd.cross(camera.up);
+    assert!(right.y.abs() < 1e-4, "rolled: up = {}", camera.up);
+    assert!(camera.up.y > 0., "upside down: up = {}", camera.up);
+}
+
+#[test]
+fn pitch_is_clamped() {
+    for direction in [1., -1.] {
+        let (mut app, camera) = locked_app();
+        common::move_mouse(&mut app, Vec2::new(0., 1000. * direction));
+        app.update();
+
+        let controlled_camera = common::camera(&app, camera);
+        let pitch = controlled_camera.fwd.y.asin().to_degrees();
+        assert!((pitch.abs() - 89.).abs() < 1e-2, "pitch {pitch}");
+        assert_level(controlled_camera);
+        let transform = app.world.get::<Transform>(camera).unwrap();
+        common::assert_vec_near(transform.forward(), controlled_camera.fwd);
+    }
+}
+
+#[test]
+fn roll_is_ignored() {
+    let (mut app, camera) = locked_app();
+    common::press(&mut app, KeyCode::E);
+    common::run(&mut app, 60);
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.up, Vec3::Y);
+    common::assert_vec_near(controlled_camera.fwd, Vec3::X);
+}
+
+#[test]
+fn looking_around_does_not_drift_into_a_roll() {
+    let (mut app, camera) = locked_app();
+    // Tracing circles with the mouse makes unlocked cameras roll
+    for frame in 0..600 {
+        let angle = frame as f32 * 0.1;
+        common::move_mouse(&mut app, 40. * Vec2::new(angle.cos(), angle.sin()));
+        app.update();
+    }
+    assert_level(common::camera(&app, camera));
+}
@@ -0,0 +1,56 @@
+//! Checks that cameras with inertia speed up and slow down smoothly.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraInertia};
+
+/// Holds W for `hold` seconds with a camera that has inertia, then lets it coast for `coast`
+/// seconds. Returns the camera's position and velocity afterwards.
+fn fly(frame_time: f32, hold: f32, coast: f32) -> (Vec3, Vec3) {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            inertia: Some(DebugCameraInertia::default()),
+            ..default()
+        },
+    );
+    common::start(&mut app, frame_time);
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, (hold / frame_time).round() as u32);
+    common::release(&mut app, KeyCode::W);
+    common::run(&mut app, (coast / frame_time).round() as u32);
+    let camera = common::camera(&app, camera);
+    (camera.position, camera.velocity)
+}
+
+#[test]
+fn accelerates_gradually() {
+    let (position, velocity) = fly(1. / 60., 0.1, 0.);
+    // Without inertia, we'd be flying at 5 units/second straight away
+    assert!(velocity.x > 0. && velocity.x < 3., "{velocity}");
+    assert!(position.x < 0.5 * 0.1 * 10.);
+}
+
+#[test]
+fn coasts_after_releasing() {
+    let (released, released_velocity) = fly(1. / 60., 1., 0.);
+    let (coasting, coasting_velocity) = fly(1. / 60., 1., 0.25);
+    assert!(coasting.x > released.x + 0.1);
+    assert!(coasting_velocity.x < released_velocity.x);
+
+    // Eventually, it comes to a stop
+    let (stopped, stopped_velocity) = fly(1. / 60., 1., 5.);
+    assert!(stopped_velocity.length() < 1e-3, "{stopped_velocity}");
+    let (stopped_later, _) = fly(1. / 60., 1., 6.);
+    assert!((stopped_later.x - stopped.x).abs() < 1e-3);
+}
+
+#[test]
+fn is_frame_rate_independent() {
+    let (at_30, velocity_at_30) = fly(1. / 30., 1., 1.);
+    let (at_144, velocity_at_144) = fly(1. / 144., 1., 1.);
+    assert!((at_30.x - at_144.x).abs() < 0.05, "{at_30} vs {at_144}");
+    assert!(velocity_at_30.abs_diff_eq(velocity_at_144, 1e-2));
+}
@@ -0,0 +1,55 @@
+//! Checks that cameras locked on to a target stay valid when it's straight above or below them.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraLookAt};
+
+fn assert_valid_basis(camera: &DebugCamera) {
+    assert!(
+        camera.fwd.is_finite() && camera.up.is_finite(),
+        "{camera:?}"
+    );
+    assert!((camera.fwd.length() - 1.).abs() < 1e-4, "{camera:?}");
+    assert!((camera.up.length() - 1.).abs() < 1e-4, "{camera:?}");
+    assert!(camera.fwd.dot(camera.up).abs() < 1e-4, "{camera:?}");
+}
+
+#[test]
+fn target_straight_below() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            position: Vec3::new(0., 10., 0.),
+            look_at: Some(DebugCameraLookAt::Point(Vec3::ZERO)),
+            ..default()
+        },
+    );
+
+    // Fly straight over the target and out the other side
+    for z in [0., -0.5, 0., 0.5, 0.] {
+        app.world.get_mut::<DebugCamera>(camera).unwrap().position.z = z;
+        app.update();
+        let camera = common::camera(&app, camera);
+        assert_valid_basis(camera);
+        let expected = (Vec3::ZERO - camera.position).normalize();
+        assert!(camera.fwd.abs_diff_eq(expected, 1e-4), "{camera:?}");
+    }
+}
+
+#[test]
+fn target_straight_above() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            look_at: Some(DebugCameraLookAt::Point(Vec3::new(0., 10., 0.))),
+            ..default()
+        },
+    );
+    app.update();
+    let camera = common::camera(&app, camera);
+    assert_valid_basis(camera);
+    assert!(camera.fwd.abs_diff_eq(Vec3::Y, 1e-4), "{camera:?}");
+}
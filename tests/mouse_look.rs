@@ -0,0 +1,55 @@
+//! Checks that mouse look rotates cameras by the same amount regardless of frame rate.
+
+mod common;
+
+use bevy::{input::mouse::MouseMotion, prelude::*};
+use bevy_debug_camera::{DebugCamera, DebugCameraPose};
+use common::assert_same_pose;
+
+/// Runs a headless app for `frames` frames lasting `frame_time` seconds each, spreading
+/// `total_motion` evenly across them. Returns the camera's resulting pose.
+fn look_around(frames: u32, frame_time: f32, total_motion: Vec2) -> DebugCameraPose {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    common::start(&mut app, frame_time);
+    for _ in 0..frames {
+        app.world.send_event(MouseMotion {
+            delta: total_motion / frames as f32,
+        });
+        app.update();
+    }
+
+    common::camera(&app, camera).pose()
+}
+
+#[test]
+fn yaw_is_frame_rate_independent() {
+    let motion = Vec2::new(300., 0.);
+    let at_30 = look_around(30, 1. / 30., motion);
+    let at_144 = look_around(144, 1. / 144., motion);
+    assert_same_pose(at_30, at_144, 1e-4);
+
+    // Make sure we actually rotated by the expected angle
+    let expected = 300. * DebugCamera::default().mouse_sensitivity;
+    assert!((at_30.fwd.angle_between(Vec3::X) - expected).abs() < 1e-4);
+}
+
+#[test]
+fn pitch_is_frame_rate_independent() {
+    let motion = Vec2::new(0., 200.);
+    assert_same_pose(
+        look_around(30, 1. / 30., motion),
+        look_around(144, 1. / 144., motion),
+        1e-4,
+    );
+}
+
+#[test]
+fn hitch_does_not_change_rotation() {
+    let motion = Vec2::new(150., 0.);
+    assert_same_pose(
+        look_around(1, 0.5, motion),
+        look_around(144, 1. / 144., motion),
+        1e-4,
+    );
+}
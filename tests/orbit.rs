@@ -0,0 +1,71 @@
+//! Checks that orbiting cameras turn and zoom around their focus point without moving it.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraMode};
+
+/// Spawns an orbiting camera at the origin, looking at `(5, 0, 0)`.
+fn orbiting_camera(app: &mut App) -> Entity {
+    common::spawn(
+        app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                mode: DebugCameraMode::Orbit,
+                ..default()
+            },
+        ),
+    )
+}
+
+#[test]
+fn mouse_orbits_around_the_focus_point() {
+    let mut app = common::app();
+    let camera = orbiting_camera(&mut app);
+    let focus = common::camera(&app, camera).focus();
+    common::start(&mut app, 1. / 60.);
+    common::move_mouse(&mut app, Vec2::new(200., 100.));
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), focus);
+    common::assert_near(controlled_camera.focus_distance, 5.);
+    assert!(controlled_camera.fwd.angle_between(Vec3::X) > 0.5);
+
+    // The transform is moved to match, still facing the focus point
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, controlled_camera.position);
+    common::assert_near(transform.translation.distance(focus), 5.);
+    common::assert_vec_near(transform.forward(), (focus - transform.translation) / 5.);
+}
+
+#[test]
+fn scrolling_zooms_to_the_focus_point() {
+    let mut app = common::app();
+    let camera = orbiting_camera(&mut app);
+    common::start(&mut app, 1. / 60.);
+    common::scroll(&mut app, 2.);
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), Vec3::new(5., 0., 0.));
+    common::assert_near(controlled_camera.focus_distance, 5. * (-0.2f32).exp());
+    // Scrolling doesn't change the speed of orbiting cameras
+    common::assert_near(controlled_camera.speed_translate, 10.);
+}
+
+#[test]
+fn up_and_down_zoom_instead_of_moving() {
+    let mut app = common::app();
+    let camera = orbiting_camera(&mut app);
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::ShiftLeft);
+    common::run(&mut app, 60);
+
+    // Holding down for a second zooms out by e^(1.5 * 0.5)
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), Vec3::new(5., 0., 0.));
+    assert!((controlled_camera.focus_distance - 5. * 0.75f32.exp()).abs() < 1e-3);
+    common::assert_near(controlled_camera.position.y, 0.);
+}
@@ -0,0 +1,63 @@
+//! Checks that 2D cameras pan and zoom across the xy plane without leaving their depth.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraMode};
+
+/// Holds `key` for a second with a 2D camera, and returns its transform and projection scale.
+fn hold(key: KeyCode) -> (Transform, f32) {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (
+            Camera2dBundle::new_with_far(1000.),
+            DebugCamera {
+                mode: DebugCameraMode::Ortho2d,
+                ..default()
+            },
+        ),
+    );
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, key);
+    common::run(&mut app, 60);
+
+    let transform = *app.world.get::<Transform>(camera).unwrap();
+    let scale = app
+        .world
+        .get::<OrthographicProjection>(camera)
+        .unwrap()
+        .scale;
+    (transform, scale)
+}
+
+#[test]
+fn keeps_its_depth() {
+    let depth = Camera2dBundle::new_with_far(1000.).transform.translation.z;
+    assert!(depth > 0.);
+    let (transform, _) = hold(KeyCode::D);
+    common::assert_near(transform.translation.z, depth);
+    common::assert_vec_near(transform.forward(), Vec3::NEG_Z);
+    common::assert_vec_near(transform.up(), Vec3::Y);
+}
+
+#[test]
+fn pans() {
+    // Keyboard input is halved, and panning is scaled by the projection's scale of 1
+    let speed = 0.5 * DebugCamera::default().speed_translate;
+    let (transform, scale) = hold(KeyCode::D);
+    assert_eq!(scale, 1.);
+    common::assert_near(transform.translation.x, speed);
+    common::assert_near(transform.translation.y, 0.);
+
+    let (transform, _) = hold(KeyCode::W);
+    common::assert_near(transform.translation.y, speed);
+}
+
+#[test]
+fn zooms() {
+    // Moving up zooms in, dividing the scale by e^1.5 per second at full strength
+    let (transform, scale) = hold(KeyCode::Space);
+    common::assert_near(scale, (-1.5f32 * 0.5).exp());
+    common::assert_near(transform.translation.x, 0.);
+}
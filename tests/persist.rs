@@ -0,0 +1,165 @@
+//! Checks that camera state, paths and splines survive being saved and loaded, and that broken
+//! files can't break cameras.
+
+#![cfg(feature = "persist")]
+
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use bevy::prelude::*;
+use bevy_debug_camera::{
+    DebugCamera, DebugCameraBookmarks, DebugCameraKeyframe, DebugCameraPath, DebugCameraPathSample,
+    DebugCameraPersist, DebugCameraPersistPlugin, DebugCameraPersistence, DebugCameraPose,
+    DebugCameraSpline, DebugCameraState, BOOKMARK_SLOTS,
+};
+
+/// A file in the temporary directory that's removed once dropped.
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> TempFile {
+        let file_name = format!("bevy_debug_camera_{name}_{}.ron", std::process::id());
+        TempFile(std::env::temp_dir().join(file_name))
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Builds an app that saves and loads state to `file` when asked to, with a camera at `pose`.
+fn app(file: &TempFile, pose: DebugCameraPose) -> (App, Entity) {
+    let mut app = common::app();
+    app.add_plugins(DebugCameraPersistPlugin {
+        persistence: DebugCameraPersistence {
+            path: file.0.clone(),
+            load_on_startup: false,
+            save_on_exit: false,
+        },
+    });
+    let mut camera = DebugCamera::default();
+    camera.set_pose(pose);
+    let camera = common::spawn(&mut app, camera);
+    app.update();
+    (app, camera)
+}
+
+fn pose_at(position: Vec3) -> DebugCameraPose {
+    DebugCameraPose {
+        position,
+        fwd: Vec3::NEG_Z,
+        up: Vec3::Y,
+    }
+}
+
+#[test]
+fn round_trip() {
+    let file = TempFile::new("round_trip");
+    let saved = pose_at(Vec3::new(1., 2., 3.));
+    let bookmark = pose_at(Vec3::new(-4., 5., 6.));
+    let (mut app, camera) = app(&file, saved);
+    app.world
+        .resource_mut::<DebugCameraBookmarks>()
+        .set(2, bookmark);
+    app.world.send_event(DebugCameraPersist::Save);
+    app.update();
+
+    // Move away and forget the bookmark, so loading has something to restore
+    app.world
+        .get_mut::<DebugCamera>(camera)
+        .unwrap()
+        .set_pose(pose_at(Vec3::ZERO));
+    *app.world.resource_mut::<DebugCameraBookmarks>() = DebugCameraBookmarks::default();
+    app.world.send_event(DebugCameraPersist::Load);
+    app.update();
+
+    assert_eq!(common::camera(&app, camera).pose(), saved);
+    assert_eq!(
+        app.world.resource::<DebugCameraBookmarks>().get(2),
+        Some(bookmark)
+    );
+}
+
+#[test]
+fn invalid_poses_are_skipped() {
+    let file = TempFile::new("invalid_poses");
+    let invalid = DebugCameraPose {
+        fwd: Vec3::ZERO,
+        ..pose_at(Vec3::ZERO)
+    };
+    let bookmark = pose_at(Vec3::new(-4., 5., 6.));
+    let mut bookmarks = [None; BOOKMARK_SLOTS];
+    bookmarks[0] = Some(bookmark);
+    bookmarks[1] = Some(DebugCameraPose {
+        position: Vec3::new(f32::NAN, 0., 0.),
+        ..bookmark
+    });
+    DebugCameraState {
+        pose: invalid,
+        speed_translate: 1.,
+        speed_rotate: 1.,
+        bookmarks,
+    }
+    .save(&file.0)
+    .unwrap();
+
+    let start = pose_at(Vec3::new(1., 2., 3.));
+    let (mut app, camera) = app(&file, start);
+    app.world.send_event(DebugCameraPersist::Load);
+    app.update();
+
+    assert_eq!(common::camera(&app, camera).pose(), start);
+    let loaded = app.world.resource::<DebugCameraBookmarks>();
+    assert_eq!(loaded.get(0), Some(bookmark));
+    assert_eq!(loaded.get(1), None);
+}
+
+#[test]
+fn malformed_files_are_rejected() {
+    let file = TempFile::new("malformed");
+    fs::write(&file.0, "(pose: (position: (1, 2").unwrap();
+    assert!(DebugCameraState::load(&file.0).is_err());
+
+    let start = pose_at(Vec3::new(1., 2., 3.));
+    let (mut app, camera) = app(&file, start);
+    app.world.send_event(DebugCameraPersist::Load);
+    app.update();
+    assert_eq!(common::camera(&app, camera).pose(), start);
+}
+
+#[test]
+fn paths_round_trip() {
+    let file = TempFile::new("path");
+    let path = DebugCameraPath {
+        samples: (0..10)
+            .map(|i| DebugCameraPathSample {
+                time: i as f32 / 3.,
+                pose: pose_at(Vec3::new(i as f32 * 0.7, 1. / 3., -2.)),
+            })
+            .collect(),
+    };
+    path.save(&file.0).unwrap();
+    assert_eq!(DebugCameraPath::load(&file.0).unwrap(), path);
+
+    fs::write(&file.0, "(samples: [(time: 0").unwrap();
+    assert!(DebugCameraPath::load(&file.0).is_err());
+}
+
+#[test]
+fn splines_round_trip() {
+    let file = TempFile::new("spline");
+    let spline = DebugCameraSpline {
+        keyframes: (0..4)
+            .map(|i| DebugCameraKeyframe {
+                pose: pose_at(Vec3::new(i as f32 * 0.7, 1. / 3., -2.)),
+                duration: 1.5,
+            })
+            .collect(),
+        closed: true,
+    };
+    spline.save(&file.0).unwrap();
+    assert_eq!(DebugCameraSpline::load(&file.0).unwrap(), spline);
+}
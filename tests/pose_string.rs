@@ -0,0 +1,53 @@
+//! Checks that camera poses survive being shared as strings, and that the format stays stable.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCameraPose, ParsePoseError};
+use common::assert_same_pose;
+
+/// Poses are rounded to the nearest millimeter and hundredth of a degree when formatted
+const TOLERANCE: f32 = 1e-3;
+
+#[test]
+fn round_trip() {
+    let pose = DebugCameraPose::from_rotation(
+        Vec3::new(12.5, -3.25, 100.),
+        Quat::from_euler(EulerRot::YXZ, 1.2, -0.4, 0.1),
+    );
+    let parsed: DebugCameraPose = pose.to_string().parse().unwrap();
+    assert_same_pose(parsed, pose, TOLERANCE);
+}
+
+#[test]
+fn stable_format() {
+    let pose = DebugCameraPose {
+        position: Vec3::new(1., 2.5, -3.),
+        fwd: Vec3::NEG_X,
+        up: Vec3::Y,
+    };
+    assert_eq!(pose.to_string(), "dcam1:1,2.5,-3,90,0,0");
+
+    // Strings written by earlier releases must keep parsing to the same pose
+    let parsed: DebugCameraPose = " dcam1:1, 2.5, -3, 90, 0, 0 ".parse().unwrap();
+    assert_same_pose(parsed, pose, TOLERANCE);
+}
+
+#[test]
+fn invalid_strings() {
+    let parse = |s: &str| s.parse::<DebugCameraPose>().unwrap_err();
+    assert_eq!(parse("1,2,3,0,0,0"), ParsePoseError::MissingPrefix);
+    assert_eq!(
+        parse("dcam99:1,2,3,0,0,0"),
+        ParsePoseError::UnsupportedVersion("dcam99".to_string())
+    );
+    assert_eq!(parse("dcam1:1,2,3"), ParsePoseError::WrongValueCount(3));
+    assert!(matches!(
+        parse("dcam1:1,2,x,0,0,0"),
+        ParsePoseError::InvalidNumber { index: 2, .. }
+    ));
+    assert_eq!(
+        parse("dcam1:1,2,3,inf,0,0"),
+        ParsePoseError::NotFinite { index: 3 }
+    );
+}
@@ -0,0 +1,118 @@
+//! Checks how cameras switch between perspective and orthographic projections, and snap to the
+//! axis-aligned views.
+
+mod common;
+
+use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy_debug_camera::{DebugCamera, DebugCameraPose};
+
+#[test]
+fn orthographic_cameras_stay_orthographic() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (
+            DebugCamera::default(),
+            Projection::Orthographic(OrthographicProjection {
+                scale: 2.,
+                ..default()
+            }),
+        ),
+    );
+    common::run(&mut app, 3);
+
+    let Projection::Orthographic(projection) = app.world.get::<Projection>(camera).unwrap() else {
+        panic!("the projection was switched to perspective");
+    };
+    assert_eq!(projection.scale, 2.);
+    assert!(common::camera(&app, camera).orthographic);
+}
+
+#[test]
+fn projection_binding_toggles_orthographic() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, (DebugCamera::default(), Projection::default()));
+    common::start(&mut app, 1. / 60.);
+    common::tap(&mut app, KeyCode::Numpad5);
+
+    // The orthographic projection shows the same area as the perspective one at the focus
+    // distance
+    let Projection::Orthographic(orthographic) = app.world.get::<Projection>(camera).unwrap()
+    else {
+        panic!("the projection wasn't switched to orthographic");
+    };
+    let perspective = PerspectiveProjection::default();
+    let ScalingMode::FixedVertical(height) = orthographic.scaling_mode else {
+        panic!("unexpected scaling mode {:?}", orthographic.scaling_mode);
+    };
+    common::assert_near(height, 2. * 5. * (perspective.fov / 2.).tan());
+    assert_eq!(orthographic.far, perspective.far);
+    assert!(common::camera(&app, camera).orthographic);
+
+    common::tap(&mut app, KeyCode::Numpad5);
+    let Projection::Perspective(restored) = app.world.get::<Projection>(camera).unwrap() else {
+        panic!("the projection wasn't switched back to perspective");
+    };
+    assert_eq!(restored.fov, perspective.fov);
+    assert!(!common::camera(&app, camera).orthographic);
+}
+
+#[test]
+fn view_bindings_snap_around_the_focus_point() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                transition_duration: 0.,
+                ..default()
+            },
+        ),
+    );
+    common::start(&mut app, 1. / 60.);
+    let focus = common::camera(&app, camera).focus();
+
+    common::tap(&mut app, KeyCode::Numpad1);
+    let front = DebugCameraPose {
+        position: focus + 5. * Vec3::Z,
+        fwd: Vec3::NEG_Z,
+        up: Vec3::Y,
+    };
+    common::assert_same_pose(common::camera(&app, camera).pose(), front, 1e-4);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, front.position);
+    common::assert_vec_near(transform.forward(), front.fwd);
+
+    // Holding the opposite modifier looks from the other side, here from below
+    common::press(&mut app, KeyCode::ControlLeft);
+    common::tap(&mut app, KeyCode::Numpad7);
+    let bottom = DebugCameraPose {
+        position: focus - 5. * Vec3::Y,
+        fwd: Vec3::Y,
+        up: Vec3::Z,
+    };
+    common::assert_same_pose(common::camera(&app, camera).pose(), bottom, 1e-4);
+}
+
+#[test]
+fn view_bindings_transition_smoothly() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::Numpad3);
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    assert!(controlled_camera.transition.is_some());
+    assert!(controlled_camera.fwd.x > 0.9, "{}", controlled_camera.fwd);
+
+    // Default transitions last half a second
+    common::run(&mut app, 31);
+    let side = DebugCameraPose {
+        position: Vec3::new(10., 0., 0.),
+        fwd: Vec3::NEG_X,
+        up: Vec3::Y,
+    };
+    common::assert_same_pose(common::camera(&app, camera).pose(), side, 1e-4);
+}
@@ -0,0 +1,87 @@
+//! Checks how the speed of cameras is changed by scrolling and by the modifier keys.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::DebugCamera;
+
+#[test]
+fn scrolling_scales_the_speed() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    common::start(&mut app, 1. / 60.);
+    common::scroll(&mut app, 2.);
+    app.update();
+    common::assert_near(
+        common::camera(&app, camera).speed_translate,
+        10. * 1.2 * 1.2,
+    );
+
+    common::scroll(&mut app, -3.);
+    app.update();
+    common::assert_near(common::camera(&app, camera).speed_translate, 10. / 1.2);
+
+    // The camera moves at its new speed
+    let position = common::camera(&app, camera).position;
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 60);
+    let moved = common::camera(&app, camera).position - position;
+    assert!((moved.x - 0.5 * 10. / 1.2).abs() < 1e-3, "moved {moved}");
+}
+
+#[test]
+fn speed_stays_within_range() {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            speed_translate_min: 1.,
+            speed_translate_max: 20.,
+            ..default()
+        },
+    );
+    common::start(&mut app, 1. / 60.);
+    common::scroll(&mut app, 100.);
+    app.update();
+    common::assert_near(common::camera(&app, camera).speed_translate, 20.);
+
+    common::scroll(&mut app, -100.);
+    app.update();
+    common::assert_near(common::camera(&app, camera).speed_translate, 1.);
+}
+
+/// Holds `keys` for a second with a default camera, and returns how far it moved.
+fn distance_moved(keys: &[KeyCode]) -> f32 {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    common::start(&mut app, 1. / 60.);
+    for &key in keys {
+        common::press(&mut app, key);
+    }
+    common::run(&mut app, 60);
+    common::camera(&app, camera).position.length()
+}
+
+#[test]
+fn modifiers_scale_translation() {
+    let normal = distance_moved(&[KeyCode::W]);
+    assert!((normal - 5.).abs() < 1e-3, "moved {normal}");
+    let boosted = distance_moved(&[KeyCode::W, KeyCode::ControlLeft]);
+    assert!((boosted - 4. * normal).abs() < 1e-3, "moved {boosted}");
+    let precise = distance_moved(&[KeyCode::W, KeyCode::AltLeft]);
+    assert!((precise - 0.25 * normal).abs() < 1e-3, "moved {precise}");
+}
+
+#[test]
+fn modifiers_scale_rotation() {
+    let mut app = common::app();
+    let camera = common::spawn(&mut app, DebugCamera::default());
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::AltLeft);
+    common::move_mouse(&mut app, Vec2::new(100., 0.));
+    app.update();
+
+    let expected = 0.25 * 100. * DebugCamera::default().mouse_sensitivity;
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_near(controlled_camera.fwd.angle_between(Vec3::X), expected);
+}
@@ -0,0 +1,92 @@
+//! Checks that top-down cameras stay above their focus point while panning and zooming.
+
+mod common;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_debug_camera::{DebugCamera, DebugCameraMode, DebugCameraTopDown};
+
+/// Spawns a top-down camera at the origin with the given zoom level. Its focus point starts at
+/// `(5, 0, 0)`.
+fn spawn_top_down(app: &mut App, zoom: f32) -> Entity {
+    common::spawn(
+        app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                mode: DebugCameraMode::TopDown,
+                top_down: DebugCameraTopDown { zoom, ..default() },
+                ..default()
+            },
+        ),
+    )
+}
+
+/// Asserts that `camera` looks down at `focus` from `height`, pitched down by `pitch` degrees.
+fn assert_above(app: &App, camera: Entity, focus: Vec3, height: f32, pitch: f32) {
+    let controlled_camera = common::camera(app, camera);
+    common::assert_vec_near(controlled_camera.focus(), focus);
+    assert!(
+        (controlled_camera.position.y - height).abs() < 1e-3,
+        "height {}",
+        controlled_camera.position.y
+    );
+    common::assert_near(-controlled_camera.fwd.y.asin(), pitch.to_radians());
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, controlled_camera.position);
+    common::assert_vec_near(transform.forward(), controlled_camera.fwd);
+}
+
+/// Asserts that `camera` is at the given zoom level.
+fn assert_near_zoom(app: &App, camera: Entity, zoom: f32) {
+    common::assert_near(common::camera(app, camera).top_down.zoom, zoom);
+}
+
+#[test]
+fn sits_above_its_focus_point() {
+    let mut app = common::app();
+    let camera = spawn_top_down(&mut app, 0.5);
+    common::start(&mut app, 1. / 60.);
+
+    // Halfway along the zoom curve, from 5 units at 45° to 50 units at 75°
+    let height = 5. * 10f32.sqrt();
+    assert_above(&app, camera, Vec3::new(5., 0., 0.), height, 60.);
+}
+
+#[test]
+fn scrolling_zooms() {
+    let mut app = common::app();
+    let camera = spawn_top_down(&mut app, 0.5);
+    common::start(&mut app, 1. / 60.);
+    common::scroll(&mut app, -10.);
+    app.update();
+
+    assert_near_zoom(&app, camera, 1.);
+    assert_above(&app, camera, Vec3::new(5., 0., 0.), 50., 75.);
+}
+
+#[test]
+fn pans_along_its_heading() {
+    let mut app = common::app();
+    let camera = spawn_top_down(&mut app, 0.);
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 60);
+
+    assert_near_zoom(&app, camera, 0.);
+    assert_above(&app, camera, Vec3::new(10., 0., 0.), 5., 45.);
+}
+
+#[test]
+fn cursor_near_the_edges_pans() {
+    let mut app = common::app();
+    let mut window = Window::default();
+    // Right at the top of the window
+    window.set_cursor_position(Some(Vec2::new(640., 5.)));
+    common::spawn(&mut app, (window, PrimaryWindow));
+    let camera = spawn_top_down(&mut app, 0.);
+    common::start(&mut app, 1. / 60.);
+    common::run(&mut app, 60);
+
+    // Edge scrolling isn't halved like the keyboard controls are
+    assert_above(&app, camera, Vec3::new(15., 0., 0.), 5., 45.);
+}
@@ -0,0 +1,85 @@
+//! Checks that walking cameras climb steps but are stopped by walls.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{
+    DebugCamera, DebugCameraGround, DebugCameraMode, GroundQuery, KeyboardBindings,
+};
+
+/// Ground at y = 0 that rises to `height` past x = 1.
+struct Ledge {
+    height: f32,
+}
+
+impl GroundQuery for Ledge {
+    fn ground_height(&self, position: Vec3) -> Option<f32> {
+        Some(if position.x > 1. { self.height } else { 0. })
+    }
+}
+
+/// Builds an app with a walking camera standing at the origin and facing `fwd`, and returns it
+/// along with the camera.
+fn walker(fwd: Vec3) -> (App, Entity) {
+    let mut app = common::app();
+    let camera = common::spawn(
+        &mut app,
+        DebugCamera {
+            mode: DebugCameraMode::Walk,
+            position: Vec3::new(0., DebugCamera::default().walk.eye_height, 0.),
+            fwd,
+            ..default()
+        },
+    );
+    (app, camera)
+}
+
+/// Walks a camera diagonally towards the ledge for a couple of seconds, and returns where it ends
+/// up.
+fn walk_into(height: f32) -> Vec3 {
+    let (mut app, camera) = walker(Vec3::new(1., 0., 1.).normalize());
+    app.insert_resource(DebugCameraGround::new(Ledge { height }));
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::W);
+    common::run(&mut app, 120);
+    common::camera(&app, camera).position
+}
+
+#[test]
+fn climbs_steps() {
+    let step = DebugCamera::default().walk.step_height * 0.5;
+    let position = walk_into(step);
+    assert!(position.x > 1., "stopped at {position}");
+    assert!((position.y - step - DebugCamera::default().walk.eye_height).abs() < 1e-4);
+}
+
+#[test]
+fn slides_along_walls() {
+    let position = walk_into(10.);
+    assert!(position.x <= 1., "climbed onto the wall at {position}");
+    assert!(position.z > 1., "stuck on the wall at {position}");
+    assert!((position.y - DebugCamera::default().walk.eye_height).abs() < 1e-4);
+}
+
+#[test]
+fn standing_up_keeps_walking() {
+    let (mut app, camera) = walker(Vec3::X);
+    common::start(&mut app, 1. / 60.);
+    let crouch = app.world.resource::<KeyboardBindings>().crouch;
+    common::press(&mut app, KeyCode::W);
+    common::press(&mut app, crouch);
+    common::run(&mut app, 30);
+
+    // Standing up raises our eyes, but the ground we're standing on shouldn't become a wall
+    let before = common::camera(&app, camera).position;
+    common::release(&mut app, crouch);
+    app.update();
+    let after = common::camera(&app, camera);
+    let step = DebugCamera::default().speed_translate * 0.5 / 60.;
+    common::assert_near(after.position.x - before.x, step);
+    assert!(after.velocity.x > 0., "{after:?}");
+
+    app.update();
+    let position = common::camera(&app, camera).position;
+    common::assert_near(position.y, DebugCamera::default().walk.eye_height);
+}
@@ -0,0 +1,112 @@
+//! Checks that zooming changes the field of view of cameras, and that dolly zooming keeps the area
+//! around the focus point the same size.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_debug_camera::{DebugCamera, DebugCameraFovZoom};
+
+/// Spawns a camera with a default perspective projection, zooming with the given smoothing.
+fn spawn_zooming(app: &mut App, smoothing: f32) -> Entity {
+    common::spawn(
+        app,
+        (
+            Camera3dBundle::default(),
+            DebugCamera {
+                fov_zoom: DebugCameraFovZoom {
+                    smoothing,
+                    ..default()
+                },
+                ..default()
+            },
+        ),
+    )
+}
+
+fn fov(app: &App, camera: Entity) -> f32 {
+    let Projection::Perspective(perspective) = app.world.get::<Projection>(camera).unwrap() else {
+        panic!("expected a perspective projection");
+    };
+    perspective.fov
+}
+
+#[test]
+fn holding_the_zoom_button_magnifies() {
+    let default_fov = PerspectiveProjection::default().fov;
+    let mut app = common::app();
+    let camera = spawn_zooming(&mut app, 0.);
+    common::start(&mut app, 1. / 60.);
+    common::press_mouse(&mut app, MouseButton::Right);
+    app.update();
+    common::assert_near(fov(&app, camera), default_fov / 4.);
+    common::assert_near(common::camera(&app, camera).fov_zoom.current, 4.);
+
+    // Looking around slows down to match
+    common::move_mouse(&mut app, Vec2::new(100., 0.));
+    app.update();
+    let expected = 100. * DebugCamera::default().mouse_sensitivity / 4.;
+    common::assert_near(
+        common::camera(&app, camera).fwd.angle_between(Vec3::X),
+        expected,
+    );
+
+    common::release_mouse(&mut app, MouseButton::Right);
+    app.update();
+    common::assert_near(fov(&app, camera), default_fov);
+}
+
+#[test]
+fn scrolling_while_zoomed_changes_the_magnification() {
+    let default_fov = PerspectiveProjection::default().fov;
+    let mut app = common::app();
+    let camera = spawn_zooming(&mut app, 0.);
+    common::start(&mut app, 1. / 60.);
+    common::press_mouse(&mut app, MouseButton::Right);
+    common::scroll(&mut app, 2.);
+    app.update();
+
+    let controlled_camera = common::camera(&app, camera);
+    let magnification = 4. * 0.2f32.exp();
+    common::assert_near(controlled_camera.fov_zoom.magnification, magnification);
+    common::assert_near(fov(&app, camera), default_fov / magnification);
+    // The wheel doesn't change the speed while zooming
+    common::assert_near(controlled_camera.speed_translate, 10.);
+}
+
+#[test]
+fn zooming_is_smoothed() {
+    let default_fov = PerspectiveProjection::default().fov;
+    let mut app = common::app();
+    let camera = spawn_zooming(&mut app, 15.);
+    common::start(&mut app, 1. / 60.);
+    common::press_mouse(&mut app, MouseButton::Right);
+    app.update();
+    let fov_after_a_frame = fov(&app, camera);
+    assert!(fov_after_a_frame < default_fov && fov_after_a_frame > default_fov / 4.);
+
+    common::run(&mut app, 60);
+    assert!((fov(&app, camera) - default_fov / 4.).abs() < 1e-4);
+}
+
+#[test]
+fn dolly_zoom_keeps_the_focus_point_framed() {
+    let default_fov = PerspectiveProjection::default().fov;
+    let mut app = common::app();
+    let camera = spawn_zooming(&mut app, 0.);
+    common::start(&mut app, 1. / 60.);
+    common::press(&mut app, KeyCode::Z);
+    common::scroll(&mut app, 2.);
+    app.update();
+
+    let new_fov = default_fov * (-0.2f32).exp();
+    common::assert_near(fov(&app, camera), new_fov);
+    let controlled_camera = common::camera(&app, camera);
+    common::assert_vec_near(controlled_camera.focus(), Vec3::new(5., 0., 0.));
+    common::assert_near(
+        controlled_camera.focus_distance * (new_fov / 2.).tan(),
+        5. * (default_fov / 2.).tan(),
+    );
+    common::assert_near(controlled_camera.speed_translate, 10.);
+    let transform = app.world.get::<Transform>(camera).unwrap();
+    common::assert_vec_near(transform.translation, controlled_camera.position);
+}